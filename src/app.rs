@@ -1,11 +1,42 @@
-use crate::board::{Board, BoardColumn, Project, Task};
+use crate::board::{self, Board, BoardColumn, Project, Task};
+use crate::command::{self, Command};
+use crate::datetime;
+use crate::keymap::KeyMap;
+use crate::settings::{Session, Settings};
 use crate::storage;
+use crate::system_clipboard;
+use crate::theme::Theme;
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+
+// how long a flash message stays in the footer
+pub const FLASH_DURATION: Duration = Duration::from_secs(4);
+
+// upper bound for a typed count, larger numbers just clamp at the board edges anyway
+const MAX_COUNT: usize = 999;
+
+// changes are written to projects.json at most this often (and always on quit)
+const SAVE_INTERVAL: Duration = Duration::from_millis(500);
 
 // application state
 pub struct App {
     pub projects: Vec<Project>,
     pub current_project: usize,
-    pub selected_project_index: usize, // for project list view
+    pub selected_project_index: usize,      // for project list view
+    pub selected_archive_index: usize,      // for archive view
+    pub selected_legend_index: usize,       // for tag legend view
+    pub selected_upcoming_index: usize,     // for upcoming view
+    pub selected_blocker_index: usize,      // for blocker picker
+    pub selected_tag_index: usize,          // for tags field in task detail view
+    pub description_scroll: usize,          // first visible line of the description in detail view
+    pub description_search: Option<String>, // query searched with / in the description
+    pub description_match: usize,           // current match of the description search
+    pub help_scroll: usize,                 // first visible line of the help view
+    pub help_page: usize,                   // help lines that fit on screen (set while drawing)
+    pub help_max_scroll: usize,             // last valid help_scroll (set while drawing)
+    pub log_scroll: usize,                  // first visible line of the activity log
+    pub log_page: usize,                    // log lines that fit on screen (set while drawing)
+    pub log_max_scroll: usize,              // last valid log_scroll (set while drawing)
     pub selected_column: usize,
     pub selected_index: usize,
     pub scroll_offset: usize,
@@ -13,8 +44,62 @@ pub struct App {
     pub should_quit: bool,
     pub input_mode: InputMode,
     pub input_buffer: String,
+    pub input_cursor: usize, // byte offset into input_buffer
     pub focused_field: TaskField,
     pub disable_saving: bool, // For testing
+    pub confirm_delete: bool, // ask y/n before deleting
+    pub pending_delete: Option<DeleteTarget>,
+    pub status_message: Option<(String, Instant)>, // flashed in the footer for a few seconds
+    pub save_error: Option<String>, // last save failure, cleared by the next successful save
+    pub unsaved_since: Option<Instant>, // first change not written to disk yet
+    #[cfg(test)]
+    pub projects_written: usize, // number of writes of projects.json so far
+    pub keymap: KeyMap,
+    pub active_tag_filter: Option<String>, // only show cards with this tag
+    pub pending_count: Option<usize>,      // vim style count typed before a motion
+    pub clipboard: Option<CutTask>,        // task cut with x, waiting to be pasted
+    pub selected_suggestion: Option<usize>, // highlighted entry of the tag picker
+    pub tag_rename_from: Option<String>,   // first answer of the rename tag prompt
+    pub expand_selected: bool,             // selected card shows its description on the board
+    pub selected_template: usize,          // column template picked for a new project
+    pub marked: HashSet<String>,           // ids of tasks marked with Space for batch moves
+    pub renaming_inline: bool, // title edit started from the board, returns to normal mode
+    pub sticky_add: bool,      // adding tasks one after another until Esc (Shift+A)
+    pub merge_source: Option<usize>, // project being merged while picking the target
+    goal_index: Option<(usize, usize, usize)>, // (wanted index, column, index) of the last h/l
+    pub settings: Settings,
+    pub theme: Theme,
+    pub no_color: bool, // --no-color or NO_COLOR: draw without any styling
+}
+
+// something waiting for a y/n confirmation before being deleted
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum DeleteTarget {
+    Task { column: usize, index: usize },
+    Column { column: usize },
+    ColumnTasks { column: usize }, // empty the column but keep it
+    Project { project: usize },
+}
+
+// a cut task and where it came from, so it can go back if never pasted
+#[derive(Clone)]
+pub struct CutTask {
+    pub task: Task,
+    pub project: usize,
+    pub column: usize,
+}
+
+impl CutTask {
+    // append the task to the column it was cut from (clamped in case that's gone)
+    fn put_back(self, projects: &mut [Project]) {
+        let project = self.project.min(projects.len() - 1);
+        let columns = &mut projects[project].board.columns;
+        if columns.is_empty() {
+            return;
+        }
+        let column = self.column.min(columns.len() - 1);
+        columns[column].tasks.push(self.task);
+    }
 }
 
 // which field is focused in task detail view
@@ -26,7 +111,7 @@ pub enum TaskField {
 }
 
 // input mode
-#[derive(PartialEq)]
+#[derive(PartialEq, Debug)]
 pub enum InputMode {
     Normal,
     AddingTask,
@@ -34,20 +119,95 @@ pub enum InputMode {
     ViewingTask,
     EditingTitle,
     EditingDescription,
+    EditingTag,
+    EditingEstimate,
+    EditingDueDate,
     ViewingHelp,
     ProjectList,
     AddingProject,
+    PickingTemplate,
     AddingColumn,
     RenamingColumn,
+    ColoringColumn,
+    ColoringProject,
+    RenamingTagEverywhere,
+    ConfirmDelete,
+    FilteringTag,
+    ViewingArchive,
+    TagLegend,
+    Upcoming,
+    PickingBlocker,
+    MovingTaskToProject,
+    MergingProject,
+    ActivityLog,
+    SearchingDescription,
+    Command,
+    Minimap,
+    TaggingColumn,
+    UntaggingColumn,
+}
+
+// start of every match of query in text, ignoring ASCII case (offsets stay valid for text)
+pub fn find_matches(text: &str, query: &str) -> Vec<usize> {
+    if query.is_empty() {
+        return Vec::new();
+    }
+    let haystack = text.to_ascii_lowercase();
+    let needle = query.to_ascii_lowercase();
+    haystack
+        .match_indices(needle.as_str())
+        .map(|(offset, _)| offset)
+        .collect()
+}
+
+impl Default for App {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl App {
     // create new app state
     pub fn new() -> Self {
-        Self {
+        board::set_custom_tag_colors(&storage::load_tag_colors());
+
+        // report bad keybindings in the footer instead of silently dropping them
+        let (keymap, errors) = match storage::load_keybindings() {
+            Ok(overrides) => KeyMap::from_config(&overrides),
+            Err(err) => (KeyMap::default(), vec![err]),
+        };
+        let (settings, warnings) = storage::load_settings();
+        // the same goes for settings that fell back to their defaults
+        let status_message = match (errors.len(), warnings.len()) {
+            (0, 0) => None,
+            (0, 1) => Some(format!("settings.json: {}", warnings[0])),
+            (0, n) => Some(format!("settings.json: {} (+{} more)", warnings[0], n - 1)),
+            (1, _) => Some(format!("keybindings.json: {}", errors[0])),
+            (n, _) => Some(format!("keybindings.json: {} (+{} more)", errors[0], n - 1)),
+        }
+        .map(|msg| (msg, Instant::now()));
+
+        let theme = Theme::by_name(&settings.theme);
+        board::set_default_columns(&settings.default_columns);
+
+        let mut app = Self {
             projects: storage::load_projects(),
             current_project: 0,
             selected_project_index: 0,
+            selected_archive_index: 0,
+            selected_legend_index: 0,
+            selected_upcoming_index: 0,
+            selected_blocker_index: 0,
+            selected_tag_index: 0,
+            description_scroll: 0,
+            description_search: None,
+            description_match: 0,
+            help_scroll: 0,
+            help_page: 1,
+            help_max_scroll: 0,
+            log_scroll: 0,
+            log_page: 1,
+            log_max_scroll: 0,
             selected_column: 0, // Default to the first column
             selected_index: 0,
             scroll_offset: 0,
@@ -55,16 +215,65 @@ impl App {
             should_quit: false,
             input_mode: InputMode::Normal,
             input_buffer: String::new(),
+            input_cursor: 0,
             focused_field: TaskField::Title,
             disable_saving: false,
+            confirm_delete: settings.confirm_delete,
+            pending_delete: None,
+            status_message,
+            save_error: None,
+            unsaved_since: None,
+            #[cfg(test)]
+            projects_written: 0,
+            keymap,
+            active_tag_filter: None,
+            pending_count: None,
+            expand_selected: false,
+            selected_template: 0,
+            marked: HashSet::new(),
+            renaming_inline: false,
+            sticky_add: false,
+            merge_source: None,
+            goal_index: None,
+            clipboard: None,
+            selected_suggestion: None,
+            tag_rename_from: None,
+            settings,
+            theme,
+            // see https://no-color.org, an empty value doesn't count
+            no_color: std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty()),
+        };
+        app.restore_session(&storage::load_session());
+        // a .tui-kanban file in the working directory picks the project to open
+        if let Some(name) = storage::load_directory_project() {
+            app.open_project_named(&name);
+        }
+        if app.auto_archive() {
+            app.save();
         }
+        app
     }
 
+    // create app state from given projects without touching disk (for tests and embedding)
     pub fn new_with_projects(projects: Vec<Project>) -> Self {
         Self {
             projects,
             current_project: 0,
             selected_project_index: 0,
+            selected_archive_index: 0,
+            selected_legend_index: 0,
+            selected_upcoming_index: 0,
+            selected_blocker_index: 0,
+            selected_tag_index: 0,
+            description_scroll: 0,
+            description_search: None,
+            description_match: 0,
+            help_scroll: 0,
+            help_page: 1,
+            help_max_scroll: 0,
+            log_scroll: 0,
+            log_page: 1,
+            log_max_scroll: 0,
             selected_column: 0,
             selected_index: 0,
             scroll_offset: 0,
@@ -72,8 +281,32 @@ impl App {
             should_quit: false,
             input_mode: InputMode::Normal,
             input_buffer: String::new(),
+            input_cursor: 0,
             focused_field: TaskField::Title,
             disable_saving: true,
+            confirm_delete: true,
+            pending_delete: None,
+            status_message: None,
+            save_error: None,
+            unsaved_since: None,
+            #[cfg(test)]
+            projects_written: 0,
+            keymap: KeyMap::default(),
+            active_tag_filter: None,
+            pending_count: None,
+            expand_selected: false,
+            selected_template: 0,
+            marked: HashSet::new(),
+            renaming_inline: false,
+            sticky_add: false,
+            merge_source: None,
+            goal_index: None,
+            clipboard: None,
+            selected_suggestion: None,
+            tag_rename_from: None,
+            settings: Settings::default(),
+            theme: Theme::DARK,
+            no_color: false,
         }
     }
 
@@ -92,59 +325,292 @@ impl App {
         &self.projects[self.current_project].name
     }
 
-    // save current state
-    fn save(&self) {
+    // mark the state as changed, flush_due_saves writes it once SAVE_INTERVAL has passed
+    // so a burst of edits on a large board costs a single write
+    fn save(&mut self) {
+        self.unsaved_since.get_or_insert_with(Instant::now);
+    }
+
+    // add a line to the current project's activity log
+    fn record(&mut self, message: impl Into<String>) {
+        self.projects[self.current_project].record(message);
+    }
+
+    // write pending changes if the first of them is at least SAVE_INTERVAL old
+    pub fn flush_due_saves(&mut self, now: Instant) {
+        if self
+            .unsaved_since
+            .is_some_and(|since| now.duration_since(since) >= SAVE_INTERVAL)
+        {
+            self.flush_saves();
+        }
+    }
+
+    // write pending changes now (on quit); failures stay visible in the header until
+    // a save works again
+    pub fn flush_saves(&mut self) {
+        if self.unsaved_since.take().is_none() {
+            return;
+        }
+        #[cfg(test)]
+        {
+            self.projects_written += 1;
+        }
+        if self.disable_saving {
+            return;
+        }
+        self.auto_archive();
+        // a failed backup shouldn't stop the save itself
+        let backup = storage::backup_projects();
+        let saved = match self.projects_with_clipboard() {
+            Some(mut projects) => storage::save_projects(&mut projects),
+            None => storage::save_projects(&mut self.projects),
+        };
+        match saved {
+            Ok(()) => {
+                self.save_error = None;
+                if let Err(err) = backup {
+                    self.flash(format!("Backup failed: {}", err));
+                }
+            }
+            Err(err) => {
+                self.save_error = Some(err.to_string());
+                self.flash(format!("Save failed: {}", err));
+            }
+        }
+    }
+
+    // archive done tasks older than the auto_archive_days setting in every project,
+    // returns whether any were archived
+    fn auto_archive(&mut self) -> bool {
+        let Some(days) = self.settings.auto_archive_days else {
+            return false;
+        };
+        let cutoff = datetime::now() - i64::from(days) * 86_400;
+        let mut archived = 0;
+        for project in &mut self.projects {
+            let count = project.archive_done_before(cutoff);
+            if count > 0 {
+                project.record(format!(
+                    "Auto-archived {} done task{}",
+                    count,
+                    if count == 1 { "" } else { "s" }
+                ));
+            }
+            archived += count;
+        }
+        if archived == 0 {
+            return false;
+        }
+        self.clamp_selection();
+        self.update_scroll();
+        self.flash(format!(
+            "Auto-archived {} done task{} older than {} days",
+            archived,
+            if archived == 1 { "" } else { "s" },
+            days
+        ));
+        true
+    }
+
+    // show a transient message in the footer
+    pub fn flash(&mut self, message: impl Into<String>) {
+        self.status_message = Some((message.into(), Instant::now()));
+    }
+
+    // the flash message, if it hasn't expired yet
+    pub fn flash_text(&self) -> Option<&str> {
+        self.status_message
+            .as_ref()
+            .filter(|(_, shown_at)| shown_at.elapsed() < FLASH_DURATION)
+            .map(|(message, _)| message.as_str())
+    }
+
+    // drop the flash message once its time is up
+    pub fn expire_flash(&mut self) {
+        if self.flash_text().is_none() {
+            self.status_message = None;
+        }
+    }
+
+    // restore last board position, clamped in case the board changed since
+    pub fn restore_session(&mut self, session: &Session) {
+        self.current_project = session.current_project.min(self.projects.len() - 1);
+        self.selected_project_index = self.current_project;
+        self.selected_column = session
+            .selected_column
+            .min(self.board().columns.len().saturating_sub(1));
+        self.selected_index = session.selected_index;
+        self.scroll_offset = session.scroll_offset;
+        self.clamp_selection();
+        self.update_scroll();
+    }
+
+    // remember the board position for next start
+    pub fn save_session(&self) {
+        if self.disable_saving {
+            return;
+        }
+        let _ = storage::save_session(&Session {
+            current_project: self.current_project,
+            selected_column: self.selected_column,
+            selected_index: self.selected_index,
+            scroll_offset: self.scroll_offset,
+        });
+    }
+
+    // save user preferences
+    fn save_settings(&mut self) {
         if self.disable_saving {
             return;
         }
-        let _ = storage::save_projects(&self.projects);
+        if let Err(err) = storage::save_settings(&self.settings) {
+            self.flash(format!("Saving settings failed: {}", err));
+        }
+    }
+
+    // switch between the dark and light theme
+    pub fn toggle_theme(&mut self) {
+        self.theme = self.theme.toggled();
+        self.settings.theme = self.theme.name.to_string();
+        self.save_settings();
+    }
+
+    // switch every card between titles only and titles with a description line
+    pub fn toggle_descriptions(&mut self) {
+        self.settings.show_descriptions = !self.settings.show_descriptions;
+        self.save_settings();
+    }
+
+    // indices of the tasks in a column that pass the active tag filter
+    pub fn visible_task_indices(&self, column_idx: usize) -> Vec<usize> {
+        self.board()
+            .get_column(column_idx)
+            .map_or(Vec::new(), |col| {
+                col.tasks
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, task)| match &self.active_tag_filter {
+                        Some(tag) => task.has_tag(tag),
+                        None => true,
+                    })
+                    .map(|(i, _)| i)
+                    .collect()
+            })
+    }
+
+    // index of the selected task if it exists and isn't filtered out
+    fn selected_task_index(&self) -> Option<usize> {
+        self.visible_task_indices(self.selected_column)
+            .contains(&self.selected_index)
+            .then_some(self.selected_index)
+    }
+
+    // the selected task, if any
+    pub fn selected_task(&self) -> Option<&Task> {
+        let index = self.selected_task_index()?;
+        self.board()
+            .columns
+            .get(self.selected_column)?
+            .tasks
+            .get(index)
+    }
+
+    fn selected_task_mut(&mut self) -> Option<&mut Task> {
+        let index = self.selected_task_index()?;
+        let column = self.selected_column;
+        self.board_mut()
+            .columns
+            .get_mut(column)?
+            .tasks
+            .get_mut(index)
     }
 
     // move selection up
     pub fn move_up(&mut self) {
-        if self.selected_index > 0 {
-            self.selected_index -= 1;
+        let visible = self.visible_task_indices(self.selected_column);
+        if let Some(&prev) = visible.iter().rev().find(|&&i| i < self.selected_index) {
+            self.selected_index = prev;
         }
     }
 
     // move selection down
     pub fn move_down(&mut self) {
-        let column_len = self
-            .board()
-            .get_column(self.selected_column)
-            .map_or(0, |col| col.tasks.len());
-        if column_len > 0 && self.selected_index < column_len - 1 {
-            self.selected_index += 1;
+        let visible = self.visible_task_indices(self.selected_column);
+        if let Some(&next) = visible.iter().find(|&&i| i > self.selected_index) {
+            self.selected_index = next;
+        }
+    }
+
+    // add a digit to the pending count (e.g. the 5 in 5j)
+    pub fn push_count_digit(&mut self, digit: u32) {
+        let count = self.pending_count.unwrap_or(0) * 10 + digit as usize;
+        self.pending_count = Some(count.min(MAX_COUNT));
+    }
+
+    // take the pending count for a motion, 1 if none was typed
+    pub fn take_count(&mut self) -> usize {
+        self.pending_count.take().unwrap_or(1).max(1)
+    }
+
+    // jump to the first visible task in the column
+    pub fn move_to_top(&mut self) {
+        if let Some(&first) = self.visible_task_indices(self.selected_column).first() {
+            self.selected_index = first;
+            self.update_scroll();
+        }
+    }
+
+    // jump to the last visible task in the column
+    pub fn move_to_bottom(&mut self) {
+        if let Some(&last) = self.visible_task_indices(self.selected_column).last() {
+            self.selected_index = last;
+            self.update_scroll();
         }
     }
 
     // move selection left
     pub fn move_left(&mut self) {
         if self.selected_column > 0 {
-            self.selected_column -= 1;
-            self.clamp_selection();
+            self.move_to_column_keeping_goal(self.selected_column - 1);
         }
     }
 
     // move selection right
     pub fn move_right(&mut self) {
-        if self.selected_column < self.board().columns.len() - 1 {
-            self.selected_column += 1;
-            self.clamp_selection();
+        if self.selected_column + 1 < self.board().columns.len() {
+            self.move_to_column_keeping_goal(self.selected_column + 1);
         }
     }
 
-    // clamp selection to no go out of bounds
+    // switch columns aiming for the row we had before passing through shorter columns,
+    // like the goal column of text editors (forgotten once the selection moves otherwise)
+    fn move_to_column_keeping_goal(&mut self, column: usize) {
+        let goal = match self.goal_index {
+            Some((goal, col, idx)) if col == self.selected_column && idx == self.selected_index => {
+                goal
+            }
+            _ => self.selected_index,
+        };
+        self.selected_column = column;
+        self.selected_index = goal;
+        self.clamp_selection();
+        self.goal_index = Some((goal, self.selected_column, self.selected_index));
+    }
+
+    // clamp selection to no go out of bounds (and onto a visible task)
     fn clamp_selection(&mut self) {
-        let column_len = self
-            .board()
-            .get_column(self.selected_column)
-            .map_or(0, |col| col.tasks.len()); // Safely get task count
-        if column_len == 0 {
+        let visible = self.visible_task_indices(self.selected_column);
+        if visible.is_empty() {
             self.selected_index = 0;
             self.scroll_offset = 0;
-        } else if self.selected_index >= column_len {
-            self.selected_index = column_len - 1;
+        } else if !visible.contains(&self.selected_index) {
+            // nearest visible task at or after the selection, else the last one
+            self.selected_index = visible
+                .iter()
+                .copied()
+                .find(|&i| i > self.selected_index)
+                .unwrap_or(visible[visible.len() - 1]);
         }
     }
 
@@ -154,24 +620,23 @@ impl App {
             return;
         }
 
-        let column_len = self
-            .board()
-            .get_column(self.selected_column)
-            .map_or(0, |col| col.tasks.len());
-        let max_scroll = if column_len > self.visible_items {
-            column_len - self.visible_items
-        } else {
-            0
-        };
+        // scroll offset counts visible (unfiltered) cards
+        let visible = self.visible_task_indices(self.selected_column);
+        let column_len = visible.len();
+        let selected_pos = visible
+            .iter()
+            .position(|&i| i == self.selected_index)
+            .unwrap_or(0);
+        let max_scroll = column_len.saturating_sub(self.visible_items);
 
         // scroll down if selected is below visible area
-        if self.selected_index >= self.scroll_offset + self.visible_items {
-            self.scroll_offset = self.selected_index - self.visible_items + 1;
+        if selected_pos >= self.scroll_offset + self.visible_items {
+            self.scroll_offset = selected_pos - self.visible_items + 1;
         }
 
         // scroll up if selected is above visible area
-        if self.selected_index < self.scroll_offset {
-            self.scroll_offset = self.selected_index;
+        if selected_pos < self.scroll_offset {
+            self.scroll_offset = selected_pos;
         }
 
         // ensure we don't scroll past the end (fixes bug when switching to columns with fewer items)
@@ -180,394 +645,3060 @@ impl App {
         }
     }
 
-    // move selected task to next column
-    pub fn move_task_forward(&mut self) {
-        let current_column_idx = self.selected_column;
-        let next_column_idx = current_column_idx + 1;
-
-        if next_column_idx < self.board().columns.len() {
-            let selected_idx = self.selected_index; // Capture before mutable borrow
+    // start prompt for a tag filter, or clear the active one
+    pub fn toggle_tag_filter(&mut self) {
+        if self.active_tag_filter.take().is_some() {
+            self.clamp_selection();
+            self.update_scroll();
+        } else {
+            self.input_mode = InputMode::FilteringTag;
+            self.input_buffer.clear();
+        }
+    }
 
-            // Remove task from current column
-            let task = {
-                let current_column = self.board_mut().get_column_mut(current_column_idx).unwrap();
-                if selected_idx < current_column.tasks.len() {
-                    current_column.tasks.remove(selected_idx)
-                } else {
-                    return; // No task to move
-                }
-            };
+    // move selected task (or all marked tasks) to next column
+    pub fn move_task_forward(&mut self) {
+        if !self.marked.is_empty() {
+            self.move_marked_tasks(true);
+            return;
+        }
+        self.move_task_to_column(self.selected_column + 1);
+    }
 
-            // Add task to next column
-            let next_column = self.board_mut().get_column_mut(next_column_idx).unwrap();
-            next_column.tasks.push(task);
+    // move selected task (or all marked tasks) to previous column
+    pub fn move_task_backward(&mut self) {
+        if !self.marked.is_empty() {
+            self.move_marked_tasks(false);
+            return;
+        }
+        if self.selected_column > 0 {
+            self.move_task_to_column(self.selected_column - 1);
+        }
+    }
 
-            self.clamp_selection();
-            self.save();
+    // mark/unmark the selected task for a batch move
+    pub fn toggle_mark(&mut self) {
+        let Some(id) = self.selected_task().map(|task| task.id.clone()) else {
+            return;
+        };
+        if !self.marked.remove(&id) {
+            self.marked.insert(id);
         }
     }
 
-    // move selected task to previous column
-    pub fn move_task_backward(&mut self) {
-        let current_column_idx = self.selected_column;
-        if current_column_idx > 0 {
-            let prev_column_idx = current_column_idx - 1;
-            let selected_idx = self.selected_index; // Capture before mutable borrow
-
-            // Remove task from current column
-            let task = {
-                let current_column = self.board_mut().get_column_mut(current_column_idx).unwrap();
-                if selected_idx < current_column.tasks.len() {
-                    current_column.tasks.remove(selected_idx)
-                } else {
-                    return; // No task to move
-                }
+    pub fn clear_marks(&mut self) {
+        self.marked.clear();
+    }
+
+    // move every marked task one column forward or back, then clear the marks
+    fn move_marked_tasks(&mut self, forward: bool) {
+        let column_count = self.board().columns.len();
+        // (from, to, index) picked up front so no task moves twice
+        let mut moves = Vec::new();
+        let mut blocked = 0;
+        for (col_idx, column) in self.board().columns.iter().enumerate() {
+            let target = if forward {
+                col_idx + 1
+            } else {
+                col_idx.wrapping_sub(1)
             };
+            if target >= column_count {
+                continue;
+            }
+            for (index, task) in column.tasks.iter().enumerate() {
+                if !self.marked.contains(&task.id) {
+                    continue;
+                }
+                // blocked tasks can't leave the first column, same as single moves
+                if col_idx == 0 && self.board().is_blocked(task) {
+                    blocked += 1;
+                    continue;
+                }
+                moves.push((col_idx, target, index));
+            }
+        }
 
-            // Add task to previous column
-            let prev_column = self.board_mut().get_column_mut(prev_column_idx).unwrap();
-            prev_column.tasks.push(task);
+        // take tasks out back to front so the indices stay valid, then put them back in board
+        // order at their old position (clamped), same as single moves
+        let mut taken = Vec::new();
+        for &(from, to, index) in moves.iter().rev() {
+            let task = self.board_mut().columns[from].tasks.remove(index);
+            taken.push((to, index, task));
+        }
+        let moved = taken.len();
+        for (to, index, mut task) in taken.into_iter().rev() {
+            task.touch();
+            let message = format!(
+                "Moved \"{}\" to {}",
+                task.title,
+                self.board().columns[to].name
+            );
+            self.record(message);
+            let tasks = &mut self.board_mut().columns[to].tasks;
+            tasks.insert(index.min(tasks.len()), task);
+        }
 
-            self.clamp_selection();
+        self.marked.clear();
+        self.clamp_selection();
+        self.update_scroll();
+        if moved > 0 {
             self.save();
         }
+        let mut message = format!("Moved {} task{}", moved, if moved == 1 { "" } else { "s" });
+        if blocked > 0 {
+            message.push_str(&format!(", {} blocked", blocked));
+        }
+        self.flash(message);
     }
 
-    // del selected task
-    pub fn delete_task(&mut self) {
+    // move selected task straight to the column at index (no-op if out of range)
+    pub fn move_task_to_column(&mut self, target_column_idx: usize) {
         let current_column_idx = self.selected_column;
-        let selected_idx = self.selected_index; // Capture before mutable borrow
-        let column = self.board_mut().get_column_mut(current_column_idx).unwrap(); // Directly get mutable column
-        if selected_idx < column.tasks.len() {
-            column.tasks.remove(selected_idx);
-            self.clamp_selection();
-            self.save();
+        if target_column_idx >= self.board().columns.len()
+            || target_column_idx == current_column_idx
+        {
+            return;
         }
-    }
+        let Some(selected_idx) = self.selected_task_index() else {
+            return; // No task to move
+        };
 
-    // Column Management Methods
+        // blocked tasks can't leave the first column until their blockers are done
+        if current_column_idx == 0 {
+            let task = &self.board().columns[current_column_idx].tasks[selected_idx];
+            if self.board().is_blocked(task) {
+                self.flash(format!("\"{}\" is blocked by unfinished tasks", task.title));
+                return;
+            }
+        }
 
-    pub fn start_adding_column(&mut self) {
-        self.input_mode = InputMode::AddingColumn;
-        self.input_buffer.clear();
+        // Remove task from current column
+        let task = self
+            .board_mut()
+            .get_column_mut(current_column_idx)
+            .unwrap()
+            .tasks
+            .remove(selected_idx);
+
+        // Add task to target column at the same position it had (or the end), keeping priority order
+        let mut task = task;
+        task.touch(); // so auto-archive counts from when it reached done
+        let message = format!(
+            "Moved \"{}\" to {}",
+            task.title,
+            self.board().columns[target_column_idx].name
+        );
+        self.record(message);
+        let target_column = self.board_mut().get_column_mut(target_column_idx).unwrap();
+        let position = selected_idx.min(target_column.tasks.len());
+        target_column.tasks.insert(position, task);
+
+        // the cursor stays in this column on the same slot, now the card that was below the
+        // moved one (or the new last card if it was the last); the scroll is pulled back too
+        self.clamp_selection();
+        self.update_scroll();
+        self.save();
     }
 
-    pub fn start_renaming_column(&mut self) {
-        if let Some(column) = self.board().get_column(self.selected_column) {
-            self.input_buffer = column.name.clone();
-            self.input_mode = InputMode::RenamingColumn;
+    // pick another project to send the selected task to
+    pub fn start_moving_task_to_project(&mut self) {
+        if self.selected_task_index().is_none() {
+            return;
+        }
+        if self.projects.len() < 2 {
+            self.flash("No other project to move the task to");
+            return;
         }
+        self.selected_project_index = self.current_project;
+        self.input_mode = InputMode::MovingTaskToProject;
     }
 
-    pub fn delete_column(&mut self) {
-        let board_len = self.board().columns.len();
-        if board_len <= 1 {
-            return; // Don't delete the last column
+    // move the selected task into the picked project's To Do (or first) column
+    pub fn move_task_to_project(&mut self) {
+        let target = self.selected_project_index;
+        if target == self.current_project || target >= self.projects.len() {
+            self.input_mode = InputMode::ViewingTask;
+            return;
         }
-
-        // Only delete if empty for safety, or prompt (simplified here: must be empty)
-        let is_empty = if let Some(col) = self.board().get_column(self.selected_column) {
-            col.tasks.is_empty()
-        } else {
-            false
+        let Some(selected_idx) = self.selected_task_index() else {
+            self.input_mode = InputMode::Normal;
+            return;
+        };
+        let target_board = &self.projects[target].board;
+        let Some(target_column) = target_board
+            .columns
+            .iter()
+            .position(|column| column.id == "todo")
+            .or((!target_board.columns.is_empty()).then_some(0))
+        else {
+            self.flash("Target project has no columns");
+            self.input_mode = InputMode::ViewingTask;
+            return;
         };
 
-        if is_empty {
-            let col_idx = self.selected_column; // Capture before mutable borrow
-            self.board_mut().columns.remove(col_idx);
-            if self.selected_column >= self.board().columns.len() {
-                self.selected_column = self.board().columns.len().saturating_sub(1);
-            }
-            self.clamp_selection();
-            self.save();
-        }
+        let column_idx = self.selected_column; // Capture before mutable borrow
+        let task = self.board_mut().columns[column_idx]
+            .tasks
+            .remove(selected_idx);
+        self.flash(format!(
+            "Moved \"{}\" to {}",
+            task.title, self.projects[target].name
+        ));
+        let source_name = self.project_name().to_string();
+        self.record(format!(
+            "Moved \"{}\" to project {}",
+            task.title, self.projects[target].name
+        ));
+        self.projects[target].record(format!(
+            "Got \"{}\" from project {}",
+            task.title, source_name
+        ));
+        self.projects[target].board.columns[target_column]
+            .tasks
+            .push(task);
+
+        // the card left this board, so fall back to its neighbour
+        self.input_mode = InputMode::Normal;
+        self.selected_project_index = self.current_project;
+        self.clamp_selection();
+        self.update_scroll();
+        self.save();
     }
 
-    pub fn move_column_left(&mut self) {
-        if self.selected_column > 0 {
-            let idx = self.selected_column;
-            self.board_mut().columns.swap(idx, idx - 1);
-            self.selected_column -= 1;
-            self.save();
+    // cut the selected task into the clipboard (only one task at a time)
+    pub fn cut_task(&mut self) {
+        if let Some(cut) = &self.clipboard {
+            let message = format!("Paste \"{}\" before cutting another task", cut.task.title);
+            self.flash(message);
+            return;
         }
+        let Some(index) = self.selected_task_index() else {
+            return;
+        };
+        let column = self.selected_column;
+        let task = self.board_mut().columns[column].tasks.remove(index);
+        self.clipboard = Some(CutTask {
+            task,
+            project: self.current_project,
+            column,
+        });
+        self.clamp_selection();
+        self.update_scroll();
     }
 
-    pub fn move_column_right(&mut self) {
-        if self.selected_column < self.board().columns.len() - 1 {
-            let idx = self.selected_column;
-            self.board_mut().columns.swap(idx, idx + 1);
-            self.selected_column += 1;
-            self.save();
-        }
+    // paste the cut task into the selected column at the selected position
+    pub fn paste_task(&mut self) {
+        let column = self.selected_column;
+        let Some(len) = self.board().get_column(column).map(|col| col.tasks.len()) else {
+            return;
+        };
+        let Some(cut) = self.clipboard.take() else {
+            return;
+        };
+        let index = self.selected_task_index().unwrap_or(len);
+        let message = format!(
+            "Moved \"{}\" to {}",
+            cut.task.title,
+            self.board().columns[column].name
+        );
+        self.record(message);
+        self.board_mut().columns[column]
+            .tasks
+            .insert(index, cut.task);
+        self.selected_index = index;
+        self.update_scroll();
+        self.save();
     }
 
-    // start input mode for adding task
-    pub fn start_adding_task(&mut self) {
-        self.input_mode = InputMode::AddingTask;
-        self.input_buffer.clear();
+    // put a task that was cut but never pasted back where it came from
+    pub fn restore_clipboard(&mut self) {
+        let Some(cut) = self.clipboard.take() else {
+            return;
+        };
+        cut.put_back(&mut self.projects);
+        self.save();
     }
 
-    // start input mode for adding tag
-    pub fn start_adding_tag(&mut self) {
-        // Only allow adding tags if there's a selected task in the selected column
-        if let Some(column) = self.board().get_column(self.selected_column) {
-            if self.selected_index < column.tasks.len() {
-                self.input_mode = InputMode::AddingTag;
-                self.input_buffer.clear();
+    // what gets written while a task is cut: the projects with that task back in its
+    // column, so a crash or kill before the paste doesn't lose it (None when nothing is cut)
+    pub fn projects_with_clipboard(&self) -> Option<Vec<Project>> {
+        let cut = self.clipboard.clone()?;
+        let mut projects = self.projects.clone();
+        cut.put_back(&mut projects);
+        Some(projects)
+    }
+
+    // del selected task (asks for confirmation first unless disabled)
+    pub fn delete_task(&mut self) {
+        let column = self.selected_column;
+        let Some(index) = self.selected_task_index() else {
+            return;
+        };
+
+        let target = DeleteTarget::Task { column, index };
+        if self.confirm_delete {
+            self.pending_delete = Some(target);
+            self.input_mode = InputMode::ConfirmDelete;
+        } else {
+            self.perform_delete(target);
+        }
+    }
+
+    // prompt shown while waiting for delete confirmation
+    pub fn delete_prompt(&self) -> Option<String> {
+        match self.pending_delete? {
+            DeleteTarget::Task { column, index } => {
+                let task = self.board().get_column(column)?.tasks.get(index)?;
+                Some(format!("Delete \"{}\"? (y/n)", task.title))
+            }
+            DeleteTarget::Column { column } => {
+                let column = self.board().get_column(column)?;
+                Some(format!(
+                    "Delete column \"{}\" and its {} tasks? (y/n)",
+                    column.name,
+                    column.tasks.len()
+                ))
+            }
+            DeleteTarget::ColumnTasks { column } => {
+                let column = self.board().get_column(column)?;
+                Some(format!(
+                    "Clear all {} tasks in \"{}\"? (y/n)",
+                    column.tasks.len(),
+                    column.name
+                ))
+            }
+            DeleteTarget::Project { project } => {
+                let project = self.projects.get(project)?;
+                Some(format!(
+                    "Delete project \"{}\" and its {} tasks? (y/n)",
+                    project.name,
+                    project.board.progress().0
+                ))
             }
         }
     }
 
-    // cancel input
-    pub fn cancel_input(&mut self) {
-        self.input_mode = InputMode::Normal;
-        self.input_buffer.clear();
+    // user answered yes: delete whatever is pending
+    pub fn confirm_pending_delete(&mut self) {
+        self.input_mode = self.mode_after_delete();
+        if let Some(target) = self.pending_delete.take() {
+            self.perform_delete(target);
+        }
     }
-    // add character to input buffer
-    pub fn input_char(&mut self, c: char) {
-        self.input_buffer.push(c);
+
+    // user answered no: keep everything
+    pub fn cancel_pending_delete(&mut self) {
+        self.input_mode = self.mode_after_delete();
+        self.pending_delete = None;
     }
 
-    // del last character from input buffer
-    pub fn input_backspace(&mut self) {
-        self.input_buffer.pop();
+    // projects are deleted from the project list, so go back there
+    fn mode_after_delete(&self) -> InputMode {
+        match self.pending_delete {
+            Some(DeleteTarget::Project { .. }) => InputMode::ProjectList,
+            _ => InputMode::Normal,
+        }
     }
 
-    // submit input
-    pub fn submit_input(&mut self) {
-        match self.input_mode {
-            InputMode::AddingTask => {
-                if !self.input_buffer.is_empty() {
-                    let task = Task::new(self.input_buffer.clone());
-                    let selected_col_idx = self.selected_column; // Capture before mutable borrow
-                    let current_column = self.board_mut().get_column_mut(selected_col_idx).unwrap();
-                    current_column.tasks.push(task);
-                    // Select the newly created task (last in the column)
-                    let column_len = current_column.tasks.len();
-                    if column_len > 0 {
-                        self.selected_index = column_len - 1;
+    // toggle between confirmed and instant delete
+    pub fn toggle_confirm_delete(&mut self) {
+        self.confirm_delete = !self.confirm_delete;
+        self.settings.confirm_delete = self.confirm_delete;
+        self.save_settings();
+    }
+
+    // actually remove the target
+    fn perform_delete(&mut self, target: DeleteTarget) {
+        match target {
+            DeleteTarget::Task { column, index } => {
+                if let Some(col) = self.board_mut().get_column_mut(column) {
+                    if index < col.tasks.len() {
+                        let task = col.tasks.remove(index);
+                        self.record(format!("Deleted \"{}\"", task.title));
+                        self.clamp_selection();
                         self.update_scroll();
+                        self.save();
                     }
-                    self.save();
                 }
             }
-            InputMode::AddingTag => {
-                if !self.input_buffer.is_empty() {
-                    let tag = self.input_buffer.clone();
-                    let current_column_idx = self.selected_column; // Capture before mutable borrow
-                    let selected_idx = self.selected_index; // Capture before mutable borrow
-                    let column = self.board_mut().get_column_mut(current_column_idx).unwrap();
-                    if selected_idx < column.tasks.len() {
-                        column.tasks[selected_idx].add_tag(tag);
-                        self.save();
+            DeleteTarget::Column { column } => {
+                // never remove the last column
+                if column < self.board().columns.len() && self.board().columns.len() > 1 {
+                    let removed = self.board_mut().columns.remove(column);
+                    self.record(format!(
+                        "Deleted column \"{}\" with {} task{}",
+                        removed.name,
+                        removed.tasks.len(),
+                        if removed.tasks.len() == 1 { "" } else { "s" }
+                    ));
+                    if self.selected_column >= self.board().columns.len() {
+                        self.selected_column = self.board().columns.len() - 1;
                     }
+                    self.clamp_selection();
+                    self.update_scroll();
+                    self.save();
                 }
             }
-            InputMode::EditingTitle => {
-                if !self.input_buffer.is_empty() {
-                    let title = self.input_buffer.clone();
-                    let current_column_idx = self.selected_column; // Capture before mutable borrow
-                    let selected_idx = self.selected_index; // Capture before mutable borrow
-                    let column = self.board_mut().get_column_mut(current_column_idx).unwrap();
-                    if selected_idx < column.tasks.len() {
-                        column.tasks[selected_idx].title = title;
-                        self.save();
-                    }
+            DeleteTarget::ColumnTasks { column } => {
+                let Some(col) = self.board_mut().get_column_mut(column) else {
+                    return;
+                };
+                let cleared = std::mem::take(&mut col.tasks);
+                let name = col.name.clone();
+                for task in &cleared {
+                    self.marked.remove(&task.id);
                 }
-                self.input_mode = InputMode::ViewingTask;
-                self.input_buffer.clear();
-                return;
+                self.record(format!(
+                    "Cleared {} task{} from {}",
+                    cleared.len(),
+                    if cleared.len() == 1 { "" } else { "s" },
+                    name
+                ));
+                self.selected_index = 0;
+                self.scroll_offset = 0;
+                self.save();
+                self.flash(format!(
+                    "Cleared {} task{}",
+                    cleared.len(),
+                    if cleared.len() == 1 { "" } else { "s" }
+                ));
             }
-            InputMode::EditingDescription => {
-                let description = self.input_buffer.clone();
-                let current_column_idx = self.selected_column; // Capture before mutable borrow
-                let selected_idx = self.selected_index; // Capture before mutable borrow
-                let column = self.board_mut().get_column_mut(current_column_idx).unwrap();
-                if selected_idx < column.tasks.len() {
-                    column.tasks[selected_idx].description = description;
-                    self.save();
-                }
-                self.input_mode = InputMode::ViewingTask;
-                self.input_buffer.clear();
-                return;
+            DeleteTarget::Project { project } => self.remove_project(project),
+        }
+    }
+
+    // Column Management Methods
+
+    pub fn start_adding_column(&mut self) {
+        self.input_mode = InputMode::AddingColumn;
+        self.input_buffer.clear();
+    }
+
+    pub fn start_renaming_column(&mut self) {
+        if let Some(column) = self.board().get_column(self.selected_column) {
+            self.set_input(column.name.clone());
+            self.input_mode = InputMode::RenamingColumn;
+        }
+    }
+
+    // delete the selected column, asking first if it still has tasks
+    pub fn delete_column(&mut self) {
+        if self.board().columns.len() <= 1 {
+            return; // Don't delete the last column
+        }
+        let Some(column) = self.board().get_column(self.selected_column) else {
+            return;
+        };
+
+        let target = DeleteTarget::Column {
+            column: self.selected_column,
+        };
+        if column.tasks.is_empty() {
+            self.perform_delete(target);
+        } else {
+            // always confirm, this can take a lot of cards with it
+            self.pending_delete = Some(target);
+            self.input_mode = InputMode::ConfirmDelete;
+        }
+    }
+
+    // remove every task from the selected column, always asking first
+    pub fn clear_column(&mut self) {
+        let Some(column) = self.board().get_column(self.selected_column) else {
+            return;
+        };
+        if column.tasks.is_empty() {
+            self.flash("Column is already empty");
+            return;
+        }
+        self.pending_delete = Some(DeleteTarget::ColumnTasks {
+            column: self.selected_column,
+        });
+        self.input_mode = InputMode::ConfirmDelete;
+    }
+
+    pub fn start_coloring_column(&mut self) {
+        if let Some(column) = self.board().get_column(self.selected_column) {
+            self.set_input(column.color.clone().unwrap_or_default());
+            self.input_mode = InputMode::ColoringColumn;
+        }
+    }
+
+    // show/hide the description of the selected card on the board
+    pub fn toggle_expand_selected(&mut self) {
+        self.expand_selected = !self.expand_selected;
+    }
+
+    // sort the selected column by title (case-insensitive), keeping the selected task selected
+    pub fn sort_column_alphabetical(&mut self) {
+        let col_idx = self.selected_column;
+        let selected_id = self.selected_task().map(|task| task.id.clone());
+        let Some(column) = self.board_mut().get_column_mut(col_idx) else {
+            return;
+        };
+        if column.tasks.is_empty() {
+            return;
+        }
+        column.tasks.sort_by_key(|task| task.title.to_lowercase());
+        if let Some(index) =
+            selected_id.and_then(|id| column.tasks.iter().position(|task| task.id == id))
+        {
+            self.selected_index = index;
+        }
+        self.clamp_selection();
+        self.update_scroll();
+        self.save();
+    }
+
+    // collapse or expand the selected column
+    pub fn toggle_collapse_column(&mut self) {
+        let col_idx = self.selected_column;
+        if let Some(column) = self.board_mut().get_column_mut(col_idx) {
+            column.collapsed = !column.collapsed;
+            self.save();
+        }
+    }
+
+    // make the selected column the backlog (collapsed, only one per board), or unmark it
+    pub fn toggle_backlog_column(&mut self) {
+        let col_idx = self.selected_column;
+        if self.board().columns.len() < 2 {
+            self.flash("A backlog needs another column to pull into");
+            return;
+        }
+        let Some(column) = self.board().get_column(col_idx) else {
+            return;
+        };
+        let name = column.name.clone();
+        if column.backlog {
+            self.board_mut().columns[col_idx].backlog = false;
+            self.flash(format!("\"{}\" is no longer the backlog", name));
+        } else {
+            for (i, column) in self.board_mut().columns.iter_mut().enumerate() {
+                column.backlog = i == col_idx;
             }
-            InputMode::AddingProject => {
-                if !self.input_buffer.is_empty() {
-                    let new_project = Project::new(self.input_buffer.clone());
-                    self.projects.push(new_project);
-                    self.current_project = self.projects.len() - 1;
-                    self.selected_project_index = self.current_project;
-                    self.save();
-                }
-                self.input_mode = InputMode::ProjectList;
-                self.input_buffer.clear();
-                return;
+            self.board_mut().columns[col_idx].collapsed = true;
+            self.flash(format!(
+                "\"{}\" is now the backlog (P pulls the next card)",
+                name
+            ));
+        }
+        self.save();
+    }
+
+    // move the top backlog card into the first other column (To Do)
+    pub fn pull_from_backlog(&mut self) {
+        let Some(backlog) = self.board().backlog_index() else {
+            self.flash("No backlog column (Shift+B marks one)");
+            return;
+        };
+        let Some(title) = self.board().columns[backlog]
+            .tasks
+            .first()
+            .map(|task| task.title.clone())
+        else {
+            self.flash("The backlog is empty");
+            return;
+        };
+        if let Some(target) = self.board_mut().pull_from_backlog() {
+            let name = self.board().columns[target].name.clone();
+            self.record(format!("Pulled \"{}\" into {}", title, name));
+            self.clamp_selection();
+            self.save();
+            self.flash(format!("Pulled \"{}\" into {}", title, name));
+        }
+    }
+
+    // widen or narrow the selected column on screen
+    pub fn resize_column(&mut self, wider: bool) {
+        let col_idx = self.selected_column;
+        if let Some(column) = self.board_mut().get_column_mut(col_idx) {
+            if column.resize(wider) {
+                self.save();
             }
-            InputMode::AddingColumn => {
-                if !self.input_buffer.is_empty() {
-                    let name = self.input_buffer.clone();
-                    let id = name.to_lowercase().replace(" ", "_");
-                    let new_column = BoardColumn::new(id, name);
-                    self.board_mut().columns.push(new_column);
-                    self.save();
+        }
+    }
+
+    pub fn move_column_left(&mut self) {
+        if self.selected_column > 0 {
+            let idx = self.selected_column;
+            self.board_mut().columns.swap(idx, idx - 1);
+            self.selected_column -= 1;
+            self.save();
+        }
+    }
+
+    pub fn move_column_right(&mut self) {
+        if self.selected_column < self.board().columns.len() - 1 {
+            let idx = self.selected_column;
+            self.board_mut().columns.swap(idx, idx + 1);
+            self.selected_column += 1;
+            self.save();
+        }
+    }
+
+    // start input mode for adding task
+    pub fn start_adding_task(&mut self) {
+        self.input_mode = InputMode::AddingTask;
+        self.input_buffer.clear();
+    }
+
+    // like start_adding_task, but the prompt stays open after each task until Esc
+    pub fn start_adding_tasks(&mut self) {
+        self.start_adding_task();
+        self.sticky_add = true;
+    }
+
+    // start input mode for adding tag
+    pub fn start_adding_tag(&mut self) {
+        // Only allow adding tags if there's a selected task in the selected column
+        if self.selected_task_index().is_some() {
+            self.input_mode = InputMode::AddingTag;
+            self.input_buffer.clear();
+            self.selected_suggestion = None;
+        }
+    }
+
+    // ask which tag to rename across the project, then its new name
+    pub fn start_renaming_tag_everywhere(&mut self) {
+        self.input_mode = InputMode::RenamingTagEverywhere;
+        self.input_buffer.clear();
+        self.tag_rename_from = None;
+    }
+
+    // rename a tag on every card of the current project, returns how many cards changed
+    pub fn rename_tag_everywhere(&mut self, old: &str, new: &str) -> usize {
+        let (old, new) = (board::normalize_tag(old), board::normalize_tag(new));
+        if old.is_empty() || new.is_empty() || old == new {
+            return 0;
+        }
+        let mut changed = 0;
+        for column in &mut self.board_mut().columns {
+            for task in &mut column.tasks {
+                if task.replace_tag(&old, &new) {
+                    changed += 1;
                 }
             }
-            InputMode::RenamingColumn => {
-                if !self.input_buffer.is_empty() {
-                    let name = self.input_buffer.clone();
-                    let col_idx = self.selected_column; // Capture before mutable borrow
-                    if let Some(column) = self.board_mut().get_column_mut(col_idx) {
-                        column.name = name;
-                        self.save();
-                    }
-                }
+        }
+        if changed > 0 {
+            self.record(format!(
+                "Renamed #{} to #{} on {} card{}",
+                old,
+                new,
+                changed,
+                if changed == 1 { "" } else { "s" }
+            ));
+            self.save();
+        }
+        if self.active_tag_filter.as_deref() == Some(old.as_str()) {
+            self.active_tag_filter = Some(new);
+        }
+        changed
+    }
+
+    // ask for a tag to add to (or remove from) every card in the selected column
+    pub fn start_tagging_column(&mut self, remove: bool) {
+        let empty = self
+            .board()
+            .get_column(self.selected_column)
+            .is_none_or(|column| column.tasks.is_empty());
+        if empty {
+            self.flash("No cards in this column");
+            return;
+        }
+        self.input_mode = if remove {
+            InputMode::UntaggingColumn
+        } else {
+            InputMode::TaggingColumn
+        };
+        self.input_buffer.clear();
+    }
+
+    // tag every card in the selected column, returns how many cards gained the tag
+    pub fn add_tag_to_column(&mut self, tag: &str) -> usize {
+        let tag = board::normalize_tag(tag);
+        let col_idx = self.selected_column; // Capture before mutable borrow
+        let Some(column) = self.board_mut().get_column_mut(col_idx) else {
+            return 0;
+        };
+        let mut changed = 0;
+        for task in &mut column.tasks {
+            if task.add_tag(tag.clone()) {
+                changed += 1;
             }
-            InputMode::Normal
-            | InputMode::ViewingTask
-            | InputMode::ViewingHelp
-            | InputMode::ProjectList => {}
         }
-        self.cancel_input();
+        let name = column.name.clone();
+        if changed > 0 {
+            self.record(format!(
+                "Tagged {} card{} in {} with #{}",
+                changed,
+                if changed == 1 { "" } else { "s" },
+                name,
+                tag
+            ));
+            self.save();
+        }
+        changed
     }
 
-    // open task detail view
-    pub fn open_task(&mut self) {
-        if let Some(column) = self.board().get_column(self.selected_column) {
-            if self.selected_index < column.tasks.len() {
-                self.input_mode = InputMode::ViewingTask;
-                self.focused_field = TaskField::Title; // Reset to title when opening
+    // remove a tag from every card in the selected column, returns how many cards had it
+    pub fn remove_tag_from_column(&mut self, tag: &str) -> usize {
+        let col_idx = self.selected_column; // Capture before mutable borrow
+        let Some(column) = self.board_mut().get_column_mut(col_idx) else {
+            return 0;
+        };
+        let mut changed = 0;
+        for task in &mut column.tasks {
+            if task.remove_tag_named(tag) {
+                changed += 1;
             }
         }
+        let name = column.name.clone();
+        if changed > 0 {
+            self.record(format!(
+                "Removed #{} from {} card{} in {}",
+                board::normalize_tag(tag),
+                changed,
+                if changed == 1 { "" } else { "s" },
+                name
+            ));
+            self.clamp_selection();
+            self.save();
+        }
+        changed
     }
 
-    // cycle to next field in task detail view
-    pub fn next_field(&mut self) {
-        self.focused_field = match self.focused_field {
-            TaskField::Title => TaskField::Tags,
-            TaskField::Tags => TaskField::Description,
-            TaskField::Description => TaskField::Title,
+    // predefined tags matching what's typed so far, minus those already on the task
+    pub fn tag_suggestions(&self) -> Vec<&'static str> {
+        let typed = board::normalize_tag(&self.input_buffer);
+        board::PREDEFINED_TAGS
+            .iter()
+            .map(|(name, _, _)| *name)
+            .filter(|tag| tag.starts_with(&typed))
+            .filter(|tag| !self.selected_task().is_some_and(|task| task.has_tag(tag)))
+            .collect()
+    }
+
+    // the highlighted suggestion, if it is still in the list
+    pub fn highlighted_suggestion(&self) -> Option<&'static str> {
+        self.selected_suggestion
+            .and_then(|i| self.tag_suggestions().get(i).copied())
+    }
+
+    pub fn suggestion_down(&mut self) {
+        let count = self.tag_suggestions().len();
+        self.selected_suggestion = match self.selected_suggestion {
+            _ if count == 0 => None,
+            None => Some(0),
+            Some(i) => Some((i + 1).min(count - 1)),
         };
     }
 
-    // start editing title
-    pub fn start_editing_title(&mut self) {
-        if let Some(column) = self.board().get_column(self.selected_column) {
-            if self.selected_index < column.tasks.len() {
-                self.input_buffer = column.tasks[self.selected_index].title.clone();
-                self.input_mode = InputMode::EditingTitle;
-            }
+    // moving up past the first suggestion goes back to free typing
+    pub fn suggestion_up(&mut self) {
+        self.selected_suggestion = self.selected_suggestion.and_then(|i| i.checked_sub(1));
+    }
+
+    // cancel input
+    pub fn cancel_input(&mut self) {
+        self.input_mode = InputMode::Normal;
+        self.input_buffer.clear();
+        self.sticky_add = false;
+    }
+    // replace the input buffer, putting the cursor at the end
+    fn set_input(&mut self, text: String) {
+        self.input_cursor = text.len();
+        self.input_buffer = text;
+    }
+
+    // cursor position kept valid even if the buffer was changed directly
+    fn cursor(&self) -> usize {
+        let mut cursor = self.input_cursor.min(self.input_buffer.len());
+        while !self.input_buffer.is_char_boundary(cursor) {
+            cursor -= 1;
         }
+        cursor
     }
 
-    // start editing description
-    pub fn start_editing_description(&mut self) {
-        if let Some(column) = self.board().get_column(self.selected_column) {
-            if self.selected_index < column.tasks.len() {
-                self.input_buffer = column.tasks[self.selected_index].description.clone();
-                self.input_mode = InputMode::EditingDescription;
+    // add character at the cursor
+    pub fn input_char(&mut self, c: char) {
+        let cursor = self.cursor();
+        self.input_buffer.insert(cursor, c);
+        self.input_cursor = cursor + c.len_utf8();
+    }
+
+    // del character before the cursor (joins lines when it's a newline)
+    pub fn input_backspace(&mut self) {
+        let cursor = self.cursor();
+        if let Some(c) = self.input_buffer[..cursor].chars().next_back() {
+            self.input_cursor = cursor - c.len_utf8();
+            self.input_buffer.remove(self.input_cursor);
+        }
+    }
+
+    // del character under the cursor
+    pub fn input_delete(&mut self) {
+        let cursor = self.cursor();
+        if cursor < self.input_buffer.len() {
+            self.input_buffer.remove(cursor);
+        }
+    }
+
+    pub fn cursor_left(&mut self) {
+        let cursor = self.cursor();
+        if let Some(c) = self.input_buffer[..cursor].chars().next_back() {
+            self.input_cursor = cursor - c.len_utf8();
+        }
+    }
+
+    pub fn cursor_right(&mut self) {
+        let cursor = self.cursor();
+        if let Some(c) = self.input_buffer[cursor..].chars().next() {
+            self.input_cursor = cursor + c.len_utf8();
+        }
+    }
+
+    // cursor as (line, char column) in the input buffer
+    pub fn cursor_position(&self) -> (usize, usize) {
+        let before = &self.input_buffer[..self.cursor()];
+        let row = before.matches('\n').count();
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        (row, before[line_start..].chars().count())
+    }
+
+    // move the cursor to a line, keeping the column where possible
+    fn set_cursor_position(&mut self, row: usize, col: usize) {
+        let mut offset = 0;
+        for (i, line) in self.input_buffer.split('\n').enumerate() {
+            if i == row {
+                let col_bytes = line.char_indices().nth(col).map_or(line.len(), |(b, _)| b);
+                self.input_cursor = offset + col_bytes;
+                return;
             }
+            offset += line.len() + 1;
         }
     }
 
-    // remove tag by index
-    pub fn remove_tag(&mut self, tag_index: usize) {
-        let current_column_idx = self.selected_column; // Capture before mutable borrow
-        let selected_idx = self.selected_index; // Capture before mutable borrow
-        if let Some(column) = self.board_mut().get_column_mut(current_column_idx) {
-            if selected_idx < column.tasks.len() {
-                let task = &mut column.tasks[selected_idx];
-                if tag_index < task.tags.len() {
-                    task.tags.remove(tag_index);
-                    self.save();
-                }
+    pub fn cursor_up(&mut self) {
+        let (row, col) = self.cursor_position();
+        if row > 0 {
+            self.set_cursor_position(row - 1, col);
+        }
+    }
+
+    pub fn cursor_down(&mut self) {
+        let (row, col) = self.cursor_position();
+        self.set_cursor_position(row + 1, col);
+    }
+
+    pub fn cursor_line_start(&mut self) {
+        let (row, _) = self.cursor_position();
+        self.set_cursor_position(row, 0);
+    }
+
+    pub fn cursor_line_end(&mut self) {
+        let (row, _) = self.cursor_position();
+        self.set_cursor_position(row, usize::MAX);
+    }
+
+    // insert text at the cursor, dropping control characters (newlines only survive in descriptions)
+    pub fn insert_text(&mut self, text: &str) {
+        let multiline = self.input_mode == InputMode::EditingDescription;
+        let text = text.replace("\r\n", "\n");
+        let mut text = text.trim_end_matches('\n');
+        if !multiline {
+            text = text.trim_start_matches('\n');
+        }
+        for c in text.chars() {
+            if c == '\n' && multiline {
+                self.input_char(c);
+            } else if c == '\n' || c == '\t' {
+                self.input_char(' ');
+            } else if !c.is_control() {
+                self.input_char(c);
             }
         }
     }
 
-    // project management
-    pub fn open_project_list(&mut self) {
-        self.input_mode = InputMode::ProjectList;
-        self.selected_project_index = self.current_project;
+    // paste the system clipboard into the input (Ctrl+V)
+    pub fn paste_from_clipboard(&mut self) {
+        match system_clipboard::read_text() {
+            Ok(text) => self.insert_text(&text),
+            Err(err) => self.flash(format!("Paste failed: {}", err)),
+        }
+    }
+
+    // del the word before the cursor and the whitespace after it (Ctrl+W)
+    pub fn delete_word_before_cursor(&mut self) {
+        let cursor = self.cursor();
+        let before = self.input_buffer[..cursor].trim_end();
+        let start = before
+            .char_indices()
+            .rev()
+            .find(|(_, c)| c.is_whitespace())
+            .map_or(0, |(i, c)| i + c.len_utf8());
+        self.input_buffer.replace_range(start..cursor, "");
+        self.input_cursor = start;
+    }
+
+    // clear the line the cursor is on (Ctrl+U), the whole input unless it's multi-line
+    pub fn clear_input_line(&mut self) {
+        let cursor = self.cursor();
+        let start = self.input_buffer[..cursor].rfind('\n').map_or(0, |i| i + 1);
+        let end = self.input_buffer[cursor..]
+            .find('\n')
+            .map_or(self.input_buffer.len(), |i| cursor + i);
+        self.input_buffer.replace_range(start..end, "");
+        self.input_cursor = start;
+    }
+
+    // whether the current mode is typing into the input buffer
+    pub fn is_editing_text(&self) -> bool {
+        matches!(
+            self.input_mode,
+            InputMode::AddingTask
+                | InputMode::AddingTag
+                | InputMode::AddingColumn
+                | InputMode::RenamingColumn
+                | InputMode::ColoringColumn
+                | InputMode::RenamingTagEverywhere
+                | InputMode::FilteringTag
+                | InputMode::AddingProject
+                | InputMode::ColoringProject
+                | InputMode::EditingTitle
+                | InputMode::EditingTag
+                | InputMode::EditingEstimate
+                | InputMode::EditingDueDate
+                | InputMode::EditingDescription
+                | InputMode::SearchingDescription
+                | InputMode::Command
+                | InputMode::TaggingColumn
+                | InputMode::UntaggingColumn
+        )
+    }
+
+    pub fn template_down(&mut self) {
+        if self.selected_template + 1 < board::COLUMN_TEMPLATES.len() {
+            self.selected_template += 1;
+        }
+    }
+
+    pub fn template_up(&mut self) {
+        self.selected_template = self.selected_template.saturating_sub(1);
+    }
+
+    // whether a project already has this name (ignoring case and surrounding spaces)
+    pub fn project_name_taken(&self, name: &str) -> bool {
+        let name = name.trim().to_lowercase();
+        self.projects
+            .iter()
+            .any(|project| project.name.trim().to_lowercase() == name)
+    }
+
+    // submit input
+    pub fn submit_input(&mut self) {
+        match self.input_mode {
+            InputMode::AddingTask => {
+                if !self.input_buffer.is_empty() {
+                    let task = Task::new(self.input_buffer.clone());
+                    let selected_col_idx = self.selected_column; // Capture before mutable borrow
+                    let current_column = self.board_mut().get_column_mut(selected_col_idx).unwrap();
+                    let message = format!("Added \"{}\" to {}", task.title, current_column.name);
+                    current_column.tasks.push(task);
+                    // Select the newly created task (last in the column)
+                    let column_len = current_column.tasks.len();
+                    if column_len > 0 {
+                        self.selected_index = column_len - 1;
+                        self.update_scroll();
+                    }
+                    self.record(message);
+                    self.save();
+                    // keep the prompt open for the next one
+                    if self.sticky_add {
+                        self.input_buffer.clear();
+                        self.input_cursor = 0;
+                        return;
+                    }
+                }
+            }
+            InputMode::AddingTag => {
+                // a highlighted suggestion wins over the typed text
+                if let Some(tag) = self.highlighted_suggestion() {
+                    self.input_buffer = tag.to_string();
+                }
+                if !self.input_buffer.is_empty() {
+                    let tag = self.input_buffer.clone();
+                    let current_column_idx = self.selected_column; // Capture before mutable borrow
+                    let selected_idx = self.selected_index; // Capture before mutable borrow
+                    let column = self.board_mut().get_column_mut(current_column_idx).unwrap();
+                    if selected_idx < column.tasks.len() {
+                        let task = &mut column.tasks[selected_idx];
+                        // duplicates and empty tags change nothing, so nothing to log or save
+                        if task.add_tag(tag.clone()) {
+                            let message = format!(
+                                "Tagged \"{}\" with #{}",
+                                task.title,
+                                board::normalize_tag(&tag)
+                            );
+                            self.record(message);
+                            self.save();
+                        }
+                    }
+                }
+            }
+            InputMode::EditingTitle => {
+                if !self.input_buffer.is_empty() {
+                    let title = self.input_buffer.clone();
+                    let current_column_idx = self.selected_column; // Capture before mutable borrow
+                    let selected_idx = self.selected_index; // Capture before mutable borrow
+                    let column = self.board_mut().get_column_mut(current_column_idx).unwrap();
+                    if selected_idx < column.tasks.len() {
+                        let old = std::mem::replace(&mut column.tasks[selected_idx].title, title);
+                        column.tasks[selected_idx].touch();
+                        let message = format!(
+                            "Renamed \"{}\" to \"{}\"",
+                            old, column.tasks[selected_idx].title
+                        );
+                        self.record(message);
+                        self.save();
+                    }
+                }
+                self.finish_editing_title();
+                return;
+            }
+            InputMode::EditingEstimate => {
+                match board::parse_estimate(&self.input_buffer) {
+                    Ok(estimate) => {
+                        if let Some(task) = self.selected_task_mut() {
+                            task.estimate = estimate;
+                            task.touch();
+                            self.save();
+                        }
+                    }
+                    Err(err) => {
+                        // keep editing so the typo can be fixed
+                        self.flash(err);
+                        return;
+                    }
+                }
+                self.input_mode = InputMode::ViewingTask;
+                self.input_buffer.clear();
+                return;
+            }
+            InputMode::Command => {
+                let input = std::mem::take(&mut self.input_buffer);
+                self.input_mode = InputMode::Normal;
+                match command::parse(&input) {
+                    Ok(command) => self.run_command(command),
+                    Err(err) => self.flash(err),
+                }
+                return;
+            }
+            InputMode::SearchingDescription => {
+                let query = std::mem::take(&mut self.input_buffer);
+                self.input_mode = InputMode::ViewingTask;
+                if query.is_empty() {
+                    self.clear_description_search();
+                    return;
+                }
+                self.description_search = Some(query);
+                self.description_match = 0;
+                if self.description_matches().is_empty() {
+                    self.flash("No match in the description");
+                }
+                self.scroll_to_description_match();
+                return;
+            }
+            InputMode::EditingDueDate => {
+                let input = self.input_buffer.trim().to_string();
+                let due_date = if input.is_empty() {
+                    None
+                } else if let Some(date) = datetime::parse_date(&input) {
+                    Some(date)
+                } else {
+                    // keep editing so the typo can be fixed
+                    self.flash(format!("\"{}\" is not a date like 2026-03-31", input));
+                    return;
+                };
+                if let Some(task) = self.selected_task_mut() {
+                    task.due_date = due_date;
+                    task.touch();
+                    self.save();
+                }
+                self.input_mode = InputMode::ViewingTask;
+                self.input_buffer.clear();
+                return;
+            }
+            InputMode::EditingTag => {
+                let tag = self.input_buffer.clone();
+                let tag_index = self.selected_tag_index;
+                if let Some(task) = self.selected_task_mut() {
+                    if task.rename_tag(tag_index, tag) {
+                        self.save();
+                    }
+                }
+                self.input_mode = InputMode::ViewingTask;
+                self.input_buffer.clear();
+                return;
+            }
+            InputMode::EditingDescription => {
+                let description = self.input_buffer.clone();
+                let current_column_idx = self.selected_column; // Capture before mutable borrow
+                let selected_idx = self.selected_index; // Capture before mutable borrow
+                let column = self.board_mut().get_column_mut(current_column_idx).unwrap();
+                if selected_idx < column.tasks.len() {
+                    let task = &mut column.tasks[selected_idx];
+                    if task.description != description {
+                        task.description = description;
+                        task.touch();
+                        let message = format!("Edited the description of \"{}\"", task.title);
+                        self.record(message);
+                        self.save();
+                    }
+                }
+                self.input_mode = InputMode::ViewingTask;
+                self.input_buffer.clear();
+                return;
+            }
+            InputMode::AddingProject => {
+                let name = self.input_buffer.trim().to_string();
+                if self.project_name_taken(&name) {
+                    // stay in the prompt so the name can be fixed
+                    self.flash(format!("A project named \"{}\" already exists", name));
+                    return;
+                }
+                if !name.is_empty() {
+                    // pick the columns next, the name waits in the input buffer
+                    self.set_input(name);
+                    self.selected_template = 0;
+                    self.input_mode = InputMode::PickingTemplate;
+                    return;
+                }
+                self.input_mode = InputMode::ProjectList;
+                self.input_buffer.clear();
+                return;
+            }
+            InputMode::ColoringProject => {
+                let color = self.input_buffer.trim().to_string();
+                if !color.is_empty() && board::parse_color(&color).is_none() {
+                    // stay in the prompt so the color can be fixed
+                    self.flash(format!("Unknown color '{}'", color));
+                    return;
+                }
+                if let Some(project) = self.projects.get_mut(self.selected_project_index) {
+                    project.color = (!color.is_empty()).then_some(color);
+                    self.save();
+                }
+                self.input_mode = InputMode::ProjectList;
+                self.input_buffer.clear();
+                return;
+            }
+            InputMode::PickingTemplate => {
+                let new_project =
+                    Project::new_from_template(self.input_buffer.clone(), self.selected_template);
+                self.projects.push(new_project);
+                self.current_project = self.projects.len() - 1;
+                self.selected_project_index = self.current_project;
+                self.save();
+                self.input_mode = InputMode::ProjectList;
+                self.input_buffer.clear();
+                return;
+            }
+            InputMode::AddingColumn => {
+                let name = self.input_buffer.trim().to_string();
+                if self.board().has_column_named(&name) {
+                    self.flash(format!("There already is a column named \"{}\"", name));
+                } else if !name.is_empty() {
+                    let id = self.board().unique_column_id(&name);
+                    self.record(format!("Added column \"{}\"", name));
+                    let new_column = BoardColumn::new(id, name);
+                    self.board_mut().columns.push(new_column);
+                    self.save();
+                }
+            }
+            InputMode::FilteringTag => {
+                let tag = board::normalize_tag(&self.input_buffer);
+                if !tag.is_empty() {
+                    self.active_tag_filter = Some(tag);
+                    self.scroll_offset = 0;
+                    self.clamp_selection();
+                    self.update_scroll();
+                }
+            }
+            InputMode::RenamingColumn => {
+                let col_idx = self.selected_column; // Capture before mutable borrow
+                let name = self.input_buffer.trim().to_string();
+                // the column may keep its own name in another case, not another column's
+                let taken = self.board().columns.iter().enumerate().any(|(i, col)| {
+                    i != col_idx && col.name.trim().to_lowercase() == name.to_lowercase()
+                });
+                if taken {
+                    self.flash(format!("There already is a column named \"{}\"", name));
+                } else if !name.is_empty() {
+                    if let Some(column) = self.board_mut().get_column_mut(col_idx) {
+                        let old = std::mem::replace(&mut column.name, name);
+                        let message = format!("Renamed column \"{}\" to \"{}\"", old, column.name);
+                        self.record(message);
+                        self.save();
+                    }
+                }
+            }
+            InputMode::ColoringColumn => {
+                let color = self.input_buffer.trim().to_string();
+                if color.is_empty() || board::parse_color(&color).is_some() {
+                    let col_idx = self.selected_column; // Capture before mutable borrow
+                    if let Some(column) = self.board_mut().get_column_mut(col_idx) {
+                        column.color = (!color.is_empty()).then_some(color);
+                        self.save();
+                    }
+                } else {
+                    // stay in the prompt so the color can be fixed
+                    self.flash(format!("Unknown color '{}'", color));
+                    return;
+                }
+            }
+            InputMode::RenamingTagEverywhere => match self.tag_rename_from.take() {
+                None => {
+                    // got the old name, now ask for the new one
+                    if !board::normalize_tag(&self.input_buffer).is_empty() {
+                        self.tag_rename_from = Some(board::normalize_tag(&self.input_buffer));
+                        self.input_buffer.clear();
+                        return;
+                    }
+                }
+                Some(old) => {
+                    let new = board::normalize_tag(&self.input_buffer);
+                    let changed = self.rename_tag_everywhere(&old, &new);
+                    self.flash(format!(
+                        "Renamed #{} to #{} on {} card{}",
+                        old,
+                        new,
+                        changed,
+                        if changed == 1 { "" } else { "s" }
+                    ));
+                }
+            },
+            InputMode::TaggingColumn => {
+                let tag = board::normalize_tag(&self.input_buffer);
+                if !tag.is_empty() {
+                    let changed = self.add_tag_to_column(&tag);
+                    self.flash(format!(
+                        "Tagged {} card{} with #{}",
+                        changed,
+                        if changed == 1 { "" } else { "s" },
+                        tag
+                    ));
+                }
+            }
+            InputMode::UntaggingColumn => {
+                let tag = board::normalize_tag(&self.input_buffer);
+                if !tag.is_empty() {
+                    let changed = self.remove_tag_from_column(&tag);
+                    self.flash(format!(
+                        "Removed #{} from {} card{}",
+                        tag,
+                        changed,
+                        if changed == 1 { "" } else { "s" }
+                    ));
+                }
+            }
+            InputMode::Normal
+            | InputMode::ViewingTask
+            | InputMode::ViewingHelp
+            | InputMode::ProjectList
+            | InputMode::ConfirmDelete
+            | InputMode::ViewingArchive
+            | InputMode::TagLegend
+            | InputMode::Upcoming
+            | InputMode::PickingBlocker
+            | InputMode::MovingTaskToProject
+            | InputMode::MergingProject
+            | InputMode::ActivityLog
+            | InputMode::Minimap => {}
+        }
+        self.cancel_input();
+    }
+
+    // open the ':' command line
+    pub fn start_command(&mut self) {
+        self.input_buffer.clear();
+        self.input_cursor = 0;
+        self.input_mode = InputMode::Command;
+    }
+
+    // carry out a command typed after ':', reusing the prompts' submit logic
+    pub fn run_command(&mut self, command: Command) {
+        match command {
+            Command::Add(title) => self.submit_as(InputMode::AddingTask, title),
+            Command::Tag(tag) => {
+                if self.selected_task().is_none() {
+                    self.flash("No task selected to tag");
+                    return;
+                }
+                self.selected_suggestion = None;
+                self.submit_as(InputMode::AddingTag, tag);
+            }
+            Command::ColumnAdd(name) => self.submit_as(InputMode::AddingColumn, name),
+            Command::ColumnRename(name) => self.submit_as(InputMode::RenamingColumn, name),
+            Command::ColumnDelete => self.delete_column(),
+            Command::Project(name) => {
+                let wanted = name.to_lowercase();
+                match self
+                    .projects
+                    .iter()
+                    .position(|project| project.name.to_lowercase() == wanted)
+                {
+                    Some(index) => {
+                        self.selected_project_index = index;
+                        self.select_project();
+                    }
+                    None => self.flash(format!("No project named '{}'", name)),
+                }
+            }
+            Command::Filter(Some(tag)) => self.submit_as(InputMode::FilteringTag, tag),
+            Command::Filter(None) => {
+                self.active_tag_filter = None;
+                self.clamp_selection();
+                self.update_scroll();
+            }
+            Command::ExportMarkdown => self.export_markdown(),
+            Command::Status => self.write_status(),
+            Command::Help => self.show_help(),
+            Command::Quit => self.should_quit = true,
+        }
+    }
+
+    // submit text as if it was typed into the prompt of the given mode
+    fn submit_as(&mut self, mode: InputMode, text: String) {
+        self.input_mode = mode;
+        self.set_input(text);
+        self.submit_input();
+    }
+
+    // open task detail view
+    pub fn open_task(&mut self) {
+        if self.selected_task_index().is_some() {
+            self.input_mode = InputMode::ViewingTask;
+            self.focused_field = TaskField::Title; // Reset to title when opening
+            self.selected_tag_index = 0;
+            self.description_scroll = 0;
+            self.description_search = None;
+        }
+    }
+
+    // type a query to find in the selected task's description
+    pub fn start_description_search(&mut self) {
+        let has_description = self
+            .selected_task()
+            .is_some_and(|task| !task.description.is_empty());
+        if has_description {
+            self.focused_field = TaskField::Description;
+            self.input_buffer.clear();
+            self.input_cursor = 0;
+            self.input_mode = InputMode::SearchingDescription;
+        }
+    }
+
+    pub fn clear_description_search(&mut self) {
+        self.description_search = None;
+        self.description_match = 0;
+    }
+
+    // byte offsets of the search matches in the description
+    pub fn description_matches(&self) -> Vec<usize> {
+        match (&self.description_search, self.selected_task()) {
+            (Some(query), Some(task)) => find_matches(&task.description, query),
+            _ => Vec::new(),
+        }
+    }
+
+    // step to the next (or previous) match, wrapping around
+    pub fn next_description_match(&mut self, forward: bool) {
+        let count = self.description_matches().len();
+        if count == 0 {
+            return;
+        }
+        self.description_match = if forward {
+            (self.description_match + 1) % count
+        } else {
+            (self.description_match + count - 1) % count
+        };
+        self.scroll_to_description_match();
+    }
+
+    // scroll so the current match is on screen, with a line of context above it
+    fn scroll_to_description_match(&mut self) {
+        let Some(&offset) = self.description_matches().get(self.description_match) else {
+            return;
+        };
+        let Some(task) = self.selected_task() else {
+            return;
+        };
+        let line = task.description[..offset].matches('\n').count();
+        self.description_scroll = line.saturating_sub(1);
+    }
+
+    // scroll the description a line at a time
+    pub fn scroll_description(&mut self, down: bool) {
+        let lines = self
+            .selected_task()
+            .map_or(1, |task| task.description.lines().count().max(1));
+        self.description_scroll = if down {
+            (self.description_scroll + 1).min(lines - 1)
+        } else {
+            self.description_scroll.saturating_sub(1)
+        };
+    }
+
+    // cycle to next field in task detail view
+    pub fn next_field(&mut self) {
+        self.focused_field = match self.focused_field {
+            TaskField::Title => TaskField::Tags,
+            TaskField::Tags => TaskField::Description,
+            TaskField::Description => TaskField::Title,
+        };
+    }
+
+    // start editing title
+    pub fn start_editing_title(&mut self) {
+        if let Some(column) = self.board().get_column(self.selected_column) {
+            if self.selected_index < column.tasks.len() {
+                self.set_input(column.tasks[self.selected_index].title.clone());
+                self.input_mode = InputMode::EditingTitle;
+                self.renaming_inline = false;
+            }
+        }
+    }
+
+    // edit the selected card's title right on the board, without the detail view
+    pub fn start_renaming_task(&mut self) {
+        self.start_editing_title();
+        if self.input_mode == InputMode::EditingTitle {
+            self.renaming_inline = true;
+        }
+    }
+
+    // leave the title edit (saved or not) for wherever it was started
+    pub fn finish_editing_title(&mut self) {
+        self.input_mode = if self.renaming_inline {
+            InputMode::Normal
+        } else {
+            InputMode::ViewingTask
+        };
+        self.renaming_inline = false;
+        self.input_buffer.clear();
+    }
+
+    // start editing the estimate, prefilled with the current one
+    pub fn start_editing_estimate(&mut self) {
+        let Some(task) = self.selected_task() else {
+            return;
+        };
+        let current = task
+            .estimate
+            .map(|hours| hours.to_string())
+            .unwrap_or_default();
+        self.set_input(current);
+        self.input_mode = InputMode::EditingEstimate;
+    }
+
+    // start editing the due date, prefilled with the current one
+    pub fn start_editing_due_date(&mut self) {
+        let Some(task) = self.selected_task() else {
+            return;
+        };
+        let current = task.due_date.clone().unwrap_or_default();
+        self.set_input(current);
+        self.input_mode = InputMode::EditingDueDate;
+    }
+
+    // start editing description
+    pub fn start_editing_description(&mut self) {
+        if let Some(column) = self.board().get_column(self.selected_column) {
+            if self.selected_index < column.tasks.len() {
+                self.set_input(column.tasks[self.selected_index].description.clone());
+                self.input_mode = InputMode::EditingDescription;
+            }
+        }
+    }
+
+    // cycle the manual color of the selected task
+    pub fn cycle_task_color(&mut self) {
+        if let Some(task) = self.selected_task_mut() {
+            task.cycle_color();
+            self.save();
+        }
+    }
+
+    // move tag selection in task detail view
+    pub fn move_tag_down(&mut self) {
+        let count = self.selected_task().map_or(0, |task| task.tags.len());
+        if self.selected_tag_index + 1 < count {
+            self.selected_tag_index += 1;
+        }
+    }
+
+    pub fn move_tag_up(&mut self) {
+        self.selected_tag_index = self.selected_tag_index.saturating_sub(1);
+    }
+
+    // start editing the selected tag
+    pub fn start_editing_tag(&mut self) {
+        let tag_index = self.selected_tag_index;
+        if let Some(tag) = self
+            .selected_task()
+            .and_then(|task| task.tags.get(tag_index))
+        {
+            self.set_input(tag.clone());
+            self.input_mode = InputMode::EditingTag;
+        }
+    }
+
+    // remove tag by index
+    pub fn remove_tag(&mut self, tag_index: usize) {
+        let current_column_idx = self.selected_column; // Capture before mutable borrow
+        let selected_idx = self.selected_index; // Capture before mutable borrow
+        if let Some(column) = self.board_mut().get_column_mut(current_column_idx) {
+            if selected_idx < column.tasks.len() {
+                let task = &mut column.tasks[selected_idx];
+                if tag_index < task.tags.len() {
+                    let tag = task.tags.remove(tag_index);
+                    task.touch();
+                    let message = format!("Removed #{} from \"{}\"", tag, task.title);
+                    // keep the highlight on a tag that still exists
+                    let count = task.tags.len();
+                    self.selected_tag_index = self.selected_tag_index.min(count.saturating_sub(1));
+                    self.record(message);
+                    self.save();
+                }
+            }
+        }
+    }
+
+    // remove the tag highlighted with j/k
+    pub fn remove_selected_tag(&mut self) {
+        self.remove_tag(self.selected_tag_index);
+    }
+
+    // get archived tasks of the current project
+    pub fn archived(&self) -> &[Task] {
+        &self.projects[self.current_project].archived
+    }
+
+    // move the selected task from the done column into the project archive
+    pub fn archive_task(&mut self) {
+        if self.board().done_column_index() != Some(self.selected_column) {
+            return; // Only finished tasks get archived
+        }
+        let Some(selected_idx) = self.selected_task_index() else {
+            return;
+        };
+        let current_column_idx = self.selected_column; // Capture before mutable borrow
+        let task = self.board_mut().columns[current_column_idx]
+            .tasks
+            .remove(selected_idx);
+        self.record(format!("Archived \"{}\"", task.title));
+        self.projects[self.current_project].archived.push(task);
+        self.clamp_selection();
+        self.save();
+    }
+
+    // list of the tags in use, opened with T
+    pub fn open_tag_legend(&mut self) {
+        self.input_mode = InputMode::TagLegend;
+        self.selected_legend_index = 0;
+    }
+
+    pub fn move_legend_up(&mut self) {
+        self.selected_legend_index = self.selected_legend_index.saturating_sub(1);
+    }
+
+    pub fn move_legend_down(&mut self) {
+        if self.selected_legend_index + 1 < self.board().tag_counts().len() {
+            self.selected_legend_index += 1;
+        }
+    }
+
+    // filter the board by the highlighted legend tag
+    pub fn filter_by_legend_tag(&mut self) {
+        let counts = self.board().tag_counts();
+        let Some((tag, _)) = counts.get(self.selected_legend_index) else {
+            return;
+        };
+        self.active_tag_filter = Some(tag.clone());
+        self.scroll_offset = 0;
+        self.clamp_selection();
+        self.update_scroll();
+        self.input_mode = InputMode::Normal;
+    }
+
+    // (column, index) of every task in the project by due date, undated ones last
+    pub fn upcoming_tasks(&self) -> Vec<(usize, usize)> {
+        let board = self.board();
+        let mut tasks: Vec<(usize, usize)> = board
+            .columns
+            .iter()
+            .enumerate()
+            .flat_map(|(col, column)| (0..column.tasks.len()).map(move |index| (col, index)))
+            .collect();
+        // stable sort keeps board order for equal dates
+        tasks.sort_by_key(|&(col, index)| {
+            let due = board.columns[col].tasks[index].due_date.clone();
+            (due.is_none(), due)
+        });
+        tasks
+    }
+
+    // zoomed out view of the board, moving in it moves the real selection
+    pub fn open_minimap(&mut self) {
+        self.input_mode = InputMode::Minimap;
+    }
+
+    pub fn open_upcoming(&mut self) {
+        self.input_mode = InputMode::Upcoming;
+        self.selected_upcoming_index = 0;
+    }
+
+    pub fn move_upcoming_up(&mut self) {
+        self.selected_upcoming_index = self.selected_upcoming_index.saturating_sub(1);
+    }
+
+    pub fn move_upcoming_down(&mut self) {
+        if self.selected_upcoming_index + 1 < self.upcoming_tasks().len() {
+            self.selected_upcoming_index += 1;
+        }
+    }
+
+    // select the highlighted upcoming task on the board
+    pub fn jump_to_upcoming(&mut self) {
+        let Some(&(column, index)) = self.upcoming_tasks().get(self.selected_upcoming_index) else {
+            return;
+        };
+        // the filter would hide the card we're jumping to
+        if !self.visible_task_indices(column).contains(&index) {
+            self.active_tag_filter = None;
+        }
+        self.selected_column = column;
+        self.selected_index = index;
+        self.update_scroll();
+        self.input_mode = InputMode::Normal;
+    }
+
+    // open the archive view
+    pub fn open_archive(&mut self) {
+        self.input_mode = InputMode::ViewingArchive;
+        self.selected_archive_index = 0;
+    }
+
+    pub fn move_archive_up(&mut self) {
+        if self.selected_archive_index > 0 {
+            self.selected_archive_index -= 1;
+        }
+    }
+
+    pub fn move_archive_down(&mut self) {
+        if self.selected_archive_index + 1 < self.archived().len() {
+            self.selected_archive_index += 1;
+        }
+    }
+
+    // restore highlighted archived task into the selected column
+    pub fn restore_archived_task(&mut self) {
+        let idx = self.selected_archive_index;
+        if idx >= self.archived().len() || self.board().columns.is_empty() {
+            return;
+        }
+        let task = self.projects[self.current_project].archived.remove(idx);
+        let col_idx = self.selected_column; // Capture before mutable borrow
+        let column = self.board_mut().get_column_mut(col_idx).unwrap();
+        let message = format!("Restored \"{}\" to {}", task.title, column.name);
+        column.tasks.push(task);
+        self.record(message);
+        if self.selected_archive_index >= self.archived().len() {
+            self.selected_archive_index = self.archived().len().saturating_sub(1);
+        }
+        self.save();
+    }
+
+    // all other tasks on the board that could block the selected one, as (column, index)
+    pub fn blocker_candidates(&self) -> Vec<(usize, usize)> {
+        let mut candidates = Vec::new();
+        for (col_idx, column) in self.board().columns.iter().enumerate() {
+            for task_idx in 0..column.tasks.len() {
+                if (col_idx, task_idx) != (self.selected_column, self.selected_index) {
+                    candidates.push((col_idx, task_idx));
+                }
+            }
+        }
+        candidates
+    }
+
+    // open the blocker picker from the task detail view
+    pub fn start_picking_blocker(&mut self) {
+        if self.selected_task_index().is_some() {
+            self.input_mode = InputMode::PickingBlocker;
+            self.selected_blocker_index = 0;
+        }
+    }
+
+    pub fn move_blocker_up(&mut self) {
+        if self.selected_blocker_index > 0 {
+            self.selected_blocker_index -= 1;
+        }
+    }
+
+    pub fn move_blocker_down(&mut self) {
+        if self.selected_blocker_index + 1 < self.blocker_candidates().len() {
+            self.selected_blocker_index += 1;
+        }
+    }
+
+    // add or remove the highlighted task as a blocker of the selected task
+    pub fn toggle_blocker(&mut self) {
+        let Some(&(col_idx, task_idx)) = self.blocker_candidates().get(self.selected_blocker_index)
+        else {
+            return;
+        };
+        let blocker_id = self.board().columns[col_idx].tasks[task_idx].id.clone();
+        let current_column_idx = self.selected_column; // Capture before mutable borrow
+        let selected_idx = self.selected_index; // Capture before mutable borrow
+        let task = &mut self.board_mut().columns[current_column_idx].tasks[selected_idx];
+        if let Some(pos) = task.blocked_by.iter().position(|id| *id == blocker_id) {
+            task.blocked_by.remove(pos);
+        } else {
+            task.blocked_by.push(blocker_id);
+        }
+        task.touch();
+        self.save();
+    }
+
+    // project management
+    pub fn open_project_list(&mut self) {
+        self.input_mode = InputMode::ProjectList;
+        self.selected_project_index = self.current_project;
+    }
+
+    pub fn select_project(&mut self) {
+        self.current_project = self.selected_project_index;
+        self.input_mode = InputMode::Normal;
+        self.selected_column = 0; // Reset to first column when changing projects
+        self.selected_index = 0;
+        self.scroll_offset = 0;
+        // with a tag filter the first card may be hidden
+        self.clamp_selection();
+        self.update_scroll();
+    }
+
+    // switch straight to the next (or previous) project, wrapping around
+    pub fn cycle_project(&mut self, forward: bool) {
+        let count = self.projects.len();
+        if count < 2 {
+            self.flash("No other project to switch to");
+            return;
+        }
+        self.selected_project_index = if forward {
+            (self.current_project + 1) % count
+        } else {
+            (self.current_project + count - 1) % count
+        };
+        self.select_project();
+        self.flash(format!("Project: {}", self.project_name()));
+    }
+
+    // switch to the project with this name, creating it if it doesn't exist yet
+    pub fn open_project_named(&mut self, name: &str) {
+        // names are unique ignoring case, so "work" opens "Work"
+        let lower = name.trim().to_lowercase();
+        match self
+            .projects
+            .iter()
+            .position(|p| p.name.trim().to_lowercase() == lower)
+        {
+            Some(index) if index == self.current_project => return, // keep the restored selection
+            Some(index) => self.selected_project_index = index,
+            None => {
+                self.projects.push(Project::new(name.to_string()));
+                self.selected_project_index = self.projects.len() - 1;
+                self.save();
+            }
+        }
+        self.select_project();
+    }
+
+    pub fn move_project_up(&mut self) {
+        if self.selected_project_index > 0 {
+            self.selected_project_index -= 1;
+        }
+    }
+
+    pub fn move_project_down(&mut self) {
+        if self.selected_project_index < self.projects.len() - 1 {
+            self.selected_project_index += 1;
+        }
+    }
+
+    // copy the highlighted project (e.g. a template) and select the copy
+    pub fn duplicate_project(&mut self) {
+        let Some(project) = self.projects.get(self.selected_project_index) else {
+            return;
+        };
+        let mut copy = project.duplicate();
+        // "X (copy)", then "X (copy 2)" and so on, so names stay unique
+        let base = copy.name.clone();
+        let mut n = 2;
+        while self.project_name_taken(&copy.name) {
+            copy.name = format!("{} {})", base.strip_suffix(')').unwrap_or(&base), n);
+            n += 1;
+        }
+        self.projects.push(copy);
+        self.selected_project_index = self.projects.len() - 1;
+        self.save();
+    }
+
+    pub fn start_adding_project(&mut self) {
+        self.input_mode = InputMode::AddingProject;
+        self.input_buffer.clear();
+    }
+
+    // set the name color of the highlighted project
+    pub fn start_coloring_project(&mut self) {
+        if let Some(project) = self.projects.get(self.selected_project_index) {
+            self.set_input(project.color.clone().unwrap_or_default());
+            self.input_mode = InputMode::ColoringProject;
+        }
+    }
+
+    // pick the project to merge the highlighted one into
+    pub fn start_merging_project(&mut self) {
+        if self.projects.len() < 2 {
+            self.flash("No other project to merge into");
+            return;
+        }
+        self.merge_source = Some(self.selected_project_index);
+        self.input_mode = InputMode::MergingProject;
+    }
+
+    // merge the project picked with start_merging_project into the highlighted one
+    pub fn merge_selected_project(&mut self) {
+        let Some(src) = self.merge_source.take() else {
+            return;
+        };
+        self.input_mode = InputMode::ProjectList;
+        if !self.merge_project_into(src, self.selected_project_index) {
+            self.flash("Pick another project to merge into");
+        }
+    }
+
+    pub fn cancel_merging_project(&mut self) {
+        if let Some(src) = self.merge_source.take() {
+            self.selected_project_index = src;
+        }
+        self.input_mode = InputMode::ProjectList;
+    }
+
+    // move all tasks (and archived ones) of src into the same-named columns of dst,
+    // then remove src; false if there is nothing to merge
+    pub fn merge_project_into(&mut self, src: usize, dst: usize) -> bool {
+        if src == dst || src >= self.projects.len() || dst >= self.projects.len() {
+            return false;
+        }
+        let source = self.projects.remove(src);
+        // indices after src shift down by one
+        let shift = |idx: usize| if idx > src { idx - 1 } else { idx };
+        let dst = shift(dst);
+
+        let targets = self.projects[dst].board.merge_from(source.board);
+        self.projects[dst].archived.extend(source.archived);
+        self.projects[dst].record(format!("Merged project {} into this one", source.name));
+        self.flash(format!(
+            "Merged {} into {}",
+            source.name, self.projects[dst].name
+        ));
+
+        // a cut task from src now returns to dst
+        if let Some(cut) = &mut self.clipboard {
+            if cut.project == src {
+                cut.project = dst;
+                cut.column = targets.get(cut.column).copied().unwrap_or(0);
+            } else {
+                cut.project = shift(cut.project);
+            }
+        }
+
+        if self.current_project == src {
+            self.current_project = dst;
+            self.selected_column = 0;
+            self.selected_index = 0;
+            self.scroll_offset = 0;
+        } else {
+            self.current_project = shift(self.current_project);
+        }
+        self.selected_project_index = dst;
+        self.clamp_selection();
+        self.save();
+        true
+    }
+
+    // delete the highlighted project after a y/n confirmation (never the last one)
+    pub fn delete_project(&mut self) {
+        if self.projects.len() <= 1 {
+            self.flash("Can't delete the only project");
+            return;
+        }
+        self.pending_delete = Some(DeleteTarget::Project {
+            project: self.selected_project_index,
+        });
+        self.input_mode = InputMode::ConfirmDelete;
+    }
+
+    fn remove_project(&mut self, project: usize) {
+        if self.projects.len() <= 1 || project >= self.projects.len() {
+            return;
+        }
+        let removed = self.projects.remove(project);
+        // indices after the removed project shift down by one
+        let shift = |idx: usize| if idx > project { idx - 1 } else { idx };
+
+        if self.current_project == project {
+            // the board changes under us, start at its top left
+            self.current_project = project.min(self.projects.len() - 1);
+            self.selected_column = 0;
+            self.selected_index = 0;
+            self.scroll_offset = 0;
+            self.marked.clear();
+            self.flash(format!(
+                "Deleted {}, now on {}",
+                removed.name,
+                self.project_name()
+            ));
+        } else {
+            self.current_project = shift(self.current_project);
+            self.flash(format!("Deleted {}", removed.name));
+        }
+        // a cut task of the removed project goes back to the active one instead
+        if let Some(cut) = &mut self.clipboard {
+            if cut.project == project {
+                cut.project = self.current_project;
+            } else {
+                cut.project = shift(cut.project);
+            }
+        }
+        self.selected_project_index =
+            shift(self.selected_project_index).min(self.projects.len() - 1);
+        self.save();
+    }
+
+    // export current project as markdown next to projects.json
+    pub fn export_markdown(&mut self) {
+        let project = &self.projects[self.current_project];
+        let message = match storage::export_project_markdown(project) {
+            Ok(path) => format!("Exported to {}", path.display()),
+            Err(err) => format!("Export failed: {}", err),
+        };
+        self.flash(message);
+    }
+
+    // write the board summary for status bars to status.json
+    pub fn write_status(&mut self) {
+        let project = &self.projects[self.current_project];
+        let message = match storage::write_status_json(project) {
+            Ok(path) => format!("Status written to {}", path.display()),
+            Err(err) => format!("Writing status failed: {}", err),
+        };
+        self.flash(message);
+    }
+
+    // show help view
+    pub fn show_help(&mut self) {
+        self.input_mode = InputMode::ViewingHelp;
+        self.help_scroll = 0;
+    }
+
+    pub fn scroll_help_down(&mut self, lines: usize) {
+        self.help_scroll = self
+            .help_scroll
+            .saturating_add(lines)
+            .min(self.help_max_scroll);
+    }
+
+    pub fn scroll_help_up(&mut self, lines: usize) {
+        self.help_scroll = self.help_scroll.saturating_sub(lines);
+    }
+
+    // recent activity of the current project, newest first
+    pub fn open_activity_log(&mut self) {
+        self.input_mode = InputMode::ActivityLog;
+        self.log_scroll = 0;
+    }
+
+    pub fn scroll_log_down(&mut self, lines: usize) {
+        self.log_scroll = self
+            .log_scroll
+            .saturating_add(lines)
+            .min(self.log_max_scroll);
+    }
+
+    pub fn scroll_log_up(&mut self, lines: usize) {
+        self.log_scroll = self.log_scroll.saturating_sub(lines);
+    }
+
+    // close detail/help view
+    pub fn close_view(&mut self) {
+        self.input_mode = InputMode::Normal;
+        self.input_buffer.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::{Board, BoardColumn, Project, Task};
+
+    fn create_test_app() -> App {
+        let board = Board {
+            columns: vec![
+                BoardColumn {
+                    id: "col1".to_string(),
+                    name: "Column 1".to_string(),
+                    tasks: vec![
+                        Task::new("Task 1".to_string()),
+                        Task::new("Task 2".to_string()),
+                    ],
+                    collapsed: false,
+                    color: None,
+                    width: None,
+                    backlog: false,
+                },
+                BoardColumn {
+                    id: "col2".to_string(),
+                    name: "Column 2".to_string(),
+                    tasks: vec![],
+                    collapsed: false,
+                    color: None,
+                    width: None,
+                    backlog: false,
+                },
+            ],
+        };
+        let project = Project {
+            name: "Test Project".to_string(),
+            board,
+            archived: Vec::new(),
+            color: None,
+            log: Vec::new(),
+        };
+        App::new_with_projects(vec![project])
+    }
+
+    #[test]
+    fn test_toggle_collapse_column() {
+        let mut app = create_test_app();
+        app.toggle_collapse_column();
+        assert!(app.board().columns[0].collapsed);
+
+        // collapsed columns can still be navigated to and from
+        app.move_right();
+        assert_eq!(app.selected_column, 1);
+        app.move_left();
+        assert_eq!(app.selected_column, 0);
+
+        app.toggle_collapse_column();
+        assert!(!app.board().columns[0].collapsed);
+    }
+
+    #[test]
+    fn test_column_color() {
+        let mut app = create_test_app();
+        app.start_coloring_column();
+        app.input_buffer = "#ff8800".to_string();
+        app.submit_input();
+        assert_eq!(app.board().columns[0].color.as_deref(), Some("#ff8800"));
+        assert_eq!(app.input_mode, InputMode::Normal);
+
+        // invalid colors are rejected with the prompt kept open, empty clears
+        app.start_coloring_column();
+        app.input_buffer = "not-a-color".to_string();
+        app.submit_input();
+        assert_eq!(app.board().columns[0].color.as_deref(), Some("#ff8800"));
+        assert!(app.status_message.is_some());
+        assert_eq!(app.input_mode, InputMode::ColoringColumn);
+
+        app.input_buffer.clear();
+        app.submit_input();
+        assert_eq!(app.board().columns[0].color, None);
+        assert_eq!(app.input_mode, InputMode::Normal);
+    }
+
+    #[test]
+    fn test_insert_text_filters_control_characters() {
+        let mut app = create_test_app();
+        app.input_mode = InputMode::AddingTask;
+        app.set_input("Fix: ".to_string());
+        app.insert_text("the\tparser\nnow\u{7}\n");
+        assert_eq!(app.input_buffer, "Fix: the parser now");
+
+        app.input_mode = InputMode::EditingDescription;
+        app.set_input(String::new());
+        app.insert_text("line one\r\nline two\n");
+        assert_eq!(app.input_buffer, "line one\nline two");
+    }
+
+    #[test]
+    fn test_duplicate_project_name_refused() {
+        let mut app = create_test_app();
+        app.input_mode = InputMode::AddingProject;
+        app.set_input("  test project ".to_string());
+        app.submit_input();
+        assert_eq!(app.projects.len(), 1);
+        assert_eq!(app.input_mode, InputMode::AddingProject);
+        assert!(app.flash_text().is_some());
+
+        app.set_input("Other".to_string());
+        app.submit_input();
+        assert_eq!(app.input_mode, InputMode::PickingTemplate);
+        app.submit_input();
+        assert_eq!(app.projects.len(), 2);
+        assert_eq!(app.input_mode, InputMode::ProjectList);
+    }
+
+    #[test]
+    fn test_color_project() {
+        let mut app = create_test_app();
+        app.input_mode = InputMode::ProjectList;
+        app.start_coloring_project();
+        assert_eq!(app.input_mode, InputMode::ColoringProject);
+
+        // unknown colors keep the prompt open
+        app.set_input("not-a-color".to_string());
+        app.submit_input();
+        assert_eq!(app.input_mode, InputMode::ColoringProject);
+        assert!(app.projects[0].color.is_none());
+
+        app.set_input("#ff8800".to_string());
+        app.submit_input();
+        assert_eq!(app.input_mode, InputMode::ProjectList);
+        assert_eq!(
+            app.projects[0].accent(),
+            Some(ratatui::style::Color::Rgb(0xff, 0x88, 0x00))
+        );
+
+        // the prompt starts with the current color, and empty resets it
+        app.start_coloring_project();
+        assert_eq!(app.input_buffer, "#ff8800");
+        app.set_input(String::new());
+        app.submit_input();
+        assert!(app.projects[0].color.is_none());
+    }
+
+    #[test]
+    fn test_new_project_from_template() {
+        let mut app = create_test_app();
+        app.input_mode = InputMode::AddingProject;
+        app.set_input("Sprint".to_string());
+        app.submit_input();
+        app.template_down();
+        app.template_down();
+        app.template_down(); // stays on the last template
+        app.submit_input();
+
+        assert_eq!(app.project_name(), "Sprint");
+        let names: Vec<&str> = app
+            .board()
+            .columns
+            .iter()
+            .map(|col| col.name.as_str())
+            .collect();
+        assert_eq!(names, ["Backlog", "Sprint", "Doing", "Review", "Done"]);
+
+        // the default template is the classic four columns
+        let project = Project::new_from_template("Dev".to_string(), 0);
+        assert_eq!(project.board.columns.len(), Board::new().columns.len());
+    }
+
+    #[test]
+    fn test_tag_legend_filters_board() {
+        let mut app = create_test_app();
+        app.board_mut().columns[0].tasks[0].add_tag("ui".to_string());
+        app.board_mut().columns[0].tasks[0].add_tag("bug".to_string());
+        app.board_mut().columns[0].tasks[1].add_tag("bug".to_string());
+        assert_eq!(
+            app.board().tag_counts(),
+            [("bug".to_string(), 2), ("ui".to_string(), 1)]
+        );
+
+        app.open_tag_legend();
+        app.move_legend_down();
+        app.move_legend_down(); // stays on the last tag
+        app.filter_by_legend_tag();
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert_eq!(app.active_tag_filter.as_deref(), Some("ui"));
+    }
+
+    #[test]
+    fn test_move_task_keeps_relative_position() {
+        let mut app = create_test_app();
+        app.board_mut().columns[1].tasks = vec![
+            Task::new("A".to_string()),
+            Task::new("B".to_string()),
+            Task::new("C".to_string()),
+        ];
+
+        // second card lands second
+        app.selected_index = 1;
+        app.move_task_forward();
+        let titles: Vec<&str> = app.board().columns[1]
+            .tasks
+            .iter()
+            .map(|task| task.title.as_str())
+            .collect();
+        assert_eq!(titles, ["A", "Task 2", "B", "C"]);
+        // selection stays in the source column
+        assert_eq!(app.selected_column, 0);
+        assert_eq!(app.selected_index, 0);
+
+        // a position past the end of the target is clamped
+        app.selected_column = 1;
+        app.selected_index = 3;
+        app.move_task_backward();
+        assert_eq!(app.board().columns[0].tasks[1].title, "C");
+    }
+
+    #[test]
+    fn test_selection_after_move_forward() {
+        let mut app = create_test_app();
+        app.board_mut().columns[0]
+            .tasks
+            .push(Task::new("Task 3".to_string()));
+
+        // the card below slides into the slot
+        app.selected_index = 1;
+        app.move_task_forward();
+        assert_eq!(app.selected_column, 0);
+        assert_eq!(app.selected_task().unwrap().title, "Task 3");
+
+        // moving the last card selects the new last card
+        app.move_task_forward();
+        assert_eq!(app.selected_index, 0);
+        assert_eq!(app.selected_task().unwrap().title, "Task 1");
+    }
+
+    #[test]
+    fn test_edit_estimate() {
+        let mut app = create_test_app();
+        app.open_task();
+        app.start_editing_estimate();
+        assert_eq!(app.input_buffer, "");
+
+        app.set_input("lots".to_string());
+        app.submit_input();
+        assert_eq!(app.input_mode, InputMode::EditingEstimate);
+        assert_eq!(app.selected_task().unwrap().estimate, None);
+
+        app.set_input("2.5h".to_string());
+        app.submit_input();
+        assert_eq!(app.input_mode, InputMode::ViewingTask);
+        assert_eq!(app.selected_task().unwrap().estimate, Some(2.5));
+
+        app.start_editing_estimate();
+        assert_eq!(app.input_buffer, "2.5");
+        app.set_input(String::new());
+        app.submit_input();
+        assert_eq!(app.selected_task().unwrap().estimate, None);
+    }
+
+    #[test]
+    fn test_rename_task_inline() {
+        let mut app = create_test_app();
+        app.start_renaming_task();
+        assert_eq!(app.input_mode, InputMode::EditingTitle);
+        assert_eq!(app.input_buffer, "Task 1");
+
+        app.set_input("Task one".to_string());
+        app.submit_input();
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert_eq!(app.selected_task().unwrap().title, "Task one");
+
+        // editing from the detail view still returns there
+        app.open_task();
+        app.start_editing_title();
+        app.finish_editing_title();
+        assert_eq!(app.input_mode, InputMode::ViewingTask);
+    }
+
+    #[test]
+    fn test_merge_project_into() {
+        let mut app = create_test_app();
+        let mut other = Project::new("Other".to_string());
+        other.board.columns[0]
+            .tasks
+            .push(Task::new("Other task".to_string()));
+        other
+            .board
+            .columns
+            .push(BoardColumn::new("ideas".to_string(), "Ideas".to_string()));
+        other.board.columns[4]
+            .tasks
+            .push(Task::new("Idea".to_string()));
+        app.projects.insert(0, other);
+        app.current_project = 1;
+
+        assert!(!app.merge_project_into(0, 0));
+        assert!(app.merge_project_into(0, 1));
+        assert_eq!(app.projects.len(), 1);
+        assert_eq!(app.current_project, 0);
+        assert_eq!(app.selected_project_index, 0);
+
+        let board = app.board();
+        let ideas = board
+            .columns
+            .iter()
+            .find(|col| col.name == "Ideas")
+            .unwrap();
+        assert_eq!(ideas.tasks[0].title, "Idea");
+        let todo = board.columns.iter().find(|col| col.name == "To Do");
+        assert!(todo.is_some_and(|col| col.tasks.iter().any(|t| t.title == "Other task")));
+    }
+
+    #[test]
+    fn test_goal_index_survives_short_columns() {
+        let mut app = create_test_app();
+        for i in 3..=5 {
+            app.board_mut().columns[0]
+                .tasks
+                .push(Task::new(format!("Task {}", i)));
+        }
+        let mut short = BoardColumn::new("col3".to_string(), "Column 3".to_string());
+        short.tasks.push(Task::new("Only".to_string()));
+        app.board_mut().columns.push(short);
+        app.selected_index = 4;
+
+        // column 1 is empty, column 2 is short
+        app.move_right();
+        assert_eq!(app.selected_index, 0);
+        app.move_right();
+        assert_eq!(app.selected_index, 0);
+        app.move_left();
+        app.move_left();
+        assert_eq!(app.selected_column, 0);
+        assert_eq!(app.selected_index, 4);
+
+        // moving vertically sets a new goal
+        app.move_right();
+        app.move_left();
+        app.move_up();
+        app.move_right();
+        app.move_left();
+        assert_eq!(app.selected_index, 3);
+    }
+
+    #[test]
+    fn test_delete_project() {
+        let mut app = create_test_app();
+        app.delete_project();
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert_eq!(app.projects.len(), 1);
+
+        app.projects.push(Project::new("Second".to_string()));
+        app.projects.push(Project::new("Third".to_string()));
+        app.current_project = 2;
+        app.open_project_list();
+        app.selected_project_index = 0;
+        app.delete_project();
+        assert_eq!(app.input_mode, InputMode::ConfirmDelete);
+        assert_eq!(
+            app.delete_prompt().unwrap(),
+            "Delete project \"Test Project\" and its 2 tasks? (y/n)"
+        );
+        app.cancel_pending_delete();
+        assert_eq!(app.input_mode, InputMode::ProjectList);
+        assert_eq!(app.projects.len(), 3);
+
+        // deleting a project before the active one keeps the same board active
+        app.delete_project();
+        app.confirm_pending_delete();
+        assert_eq!(app.input_mode, InputMode::ProjectList);
+        assert_eq!(app.projects.len(), 2);
+        assert_eq!(app.project_name(), "Third");
+
+        // deleting the active one moves to a neighbour and resets the selection
+        app.selected_column = 2;
+        app.selected_project_index = 1;
+        app.delete_project();
+        app.confirm_pending_delete();
+        assert_eq!(app.project_name(), "Second");
+        assert_eq!(app.selected_column, 0);
+    }
+
+    #[test]
+    fn test_find_matches() {
+        assert_eq!(find_matches("Foo bar foo", "foo"), vec![0, 8]);
+        assert_eq!(find_matches("aaaa", "aa"), vec![0, 2]);
+        assert_eq!(find_matches("naïve Naïve", "NAÏVE"), Vec::<usize>::new());
+        assert_eq!(find_matches("naïve Naïve", "naïve"), vec![0, 7]);
+        assert!(find_matches("text", "").is_empty());
+    }
+
+    #[test]
+    fn test_description_search() {
+        let mut app = create_test_app();
+        app.selected_task_mut().unwrap().description =
+            "first todo\nsecond\nthird\nfourth TODO".to_string();
+        app.open_task();
+        app.start_description_search();
+        assert_eq!(app.input_mode, InputMode::SearchingDescription);
+
+        app.insert_text("todo");
+        app.submit_input();
+        assert_eq!(app.input_mode, InputMode::ViewingTask);
+        assert_eq!(app.description_matches().len(), 2);
+        assert_eq!(app.description_scroll, 0);
+
+        app.next_description_match(true);
+        assert_eq!(app.description_match, 1);
+        assert_eq!(app.description_scroll, 2);
+        app.next_description_match(true);
+        assert_eq!(app.description_match, 0);
+
+        app.clear_description_search();
+        assert!(app.description_matches().is_empty());
+    }
+
+    #[test]
+    fn test_run_commands() {
+        let mut app = create_test_app();
+        app.start_command();
+        app.insert_text("add From the command line");
+        app.submit_input();
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert_eq!(app.selected_task().unwrap().title, "From the command line");
+
+        app.run_command(Command::Tag("bug".to_string()));
+        assert!(app.selected_task().unwrap().has_tag("bug"));
+
+        app.run_command(Command::ColumnAdd("Review".to_string()));
+        assert_eq!(app.board().columns.last().unwrap().name, "Review");
+
+        app.start_command();
+        app.insert_text("fly away");
+        app.submit_input();
+        assert!(app.flash_text().unwrap().contains("unknown command 'fly'"));
+
+        app.run_command(Command::Quit);
+        assert!(app.should_quit);
+    }
+
+    #[test]
+    fn test_move_marked_tasks() {
+        let mut app = create_test_app();
+        app.board_mut().columns[0]
+            .tasks
+            .push(Task::new("Task 3".to_string()));
+
+        app.toggle_mark();
+        app.selected_index = 2;
+        app.toggle_mark();
+        app.selected_index = 1;
+        app.toggle_mark();
+        app.toggle_mark(); // unmarked again
+        assert_eq!(app.marked.len(), 2);
+
+        app.move_task_forward();
+        assert!(app.marked.is_empty());
+        let titles = |app: &App, col: usize| -> Vec<String> {
+            app.board().columns[col]
+                .tasks
+                .iter()
+                .map(|task| task.title.clone())
+                .collect()
+        };
+        assert_eq!(titles(&app, 0), ["Task 2"]);
+        assert_eq!(titles(&app, 1), ["Task 1", "Task 3"]);
+
+        // already in the last column: nothing moves
+        app.selected_column = 1;
+        app.selected_index = 0;
+        app.toggle_mark();
+        app.move_task_forward();
+        assert_eq!(titles(&app, 1), ["Task 1", "Task 3"]);
+        assert!(app.marked.is_empty());
+
+        // moved cards keep their positions among the cards already there
+        for title in ["Task 4", "Task 5"] {
+            app.board_mut().columns[0]
+                .tasks
+                .push(Task::new(title.to_string()));
+        }
+        app.selected_column = 0;
+        app.selected_index = 0;
+        app.toggle_mark();
+        app.selected_index = 2;
+        app.toggle_mark();
+        app.move_task_forward();
+        assert_eq!(titles(&app, 0), ["Task 4"]);
+        assert_eq!(titles(&app, 1), ["Task 2", "Task 1", "Task 5", "Task 3"]);
+    }
+
+    #[test]
+    fn test_saves_are_batched() {
+        let mut app = create_test_app();
+        for i in 0..500 {
+            app.input_buffer = format!("Task {}", i);
+            app.input_mode = InputMode::AddingTask;
+            app.submit_input();
+        }
+        assert_eq!(app.board().columns[0].tasks.len(), 502);
+
+        // nothing written until the interval has passed, then everything at once
+        let first_change = app.unsaved_since.unwrap();
+        app.flush_due_saves(first_change);
+        assert_eq!(app.projects_written, 0);
+        app.flush_due_saves(first_change + SAVE_INTERVAL);
+        assert_eq!(app.projects_written, 1);
+        assert!(app.unsaved_since.is_none());
+
+        // quitting writes whatever is left, and nothing when it's all saved
+        app.move_task_forward();
+        app.flush_saves();
+        app.flush_saves();
+        assert_eq!(app.projects_written, 2);
+    }
+
+    #[test]
+    fn test_auto_archive() {
+        let mut app = create_test_app();
+        assert!(!app.auto_archive());
+
+        app.settings.auto_archive_days = Some(7);
+        app.board_mut().columns[1].tasks = vec![Task::new("Fresh".to_string())];
+        let mut stale = Task::new("Stale".to_string());
+        stale.updated_at = Some(datetime::now() - 8 * 86_400);
+        app.board_mut().columns[1].tasks.push(stale);
+
+        assert!(app.auto_archive());
+        assert_eq!(app.archived().len(), 1);
+        assert_eq!(app.archived()[0].title, "Stale");
+        assert_eq!(app.board().columns[1].tasks.len(), 1);
+        assert!(app
+            .flash_text()
+            .unwrap()
+            .contains("Auto-archived 1 done task"));
+    }
+
+    #[test]
+    fn test_cycle_project() {
+        let mut app = create_test_app();
+        app.cycle_project(true);
+        assert_eq!(app.current_project, 0);
+
+        app.projects.push(Project::new("Second".to_string()));
+        app.selected_column = 1;
+        app.cycle_project(true);
+        assert_eq!(app.current_project, 1);
+        assert_eq!(app.selected_column, 0);
+        assert_eq!(app.flash_text(), Some("Project: Second"));
+
+        app.cycle_project(true);
+        assert_eq!(app.current_project, 0);
+        app.cycle_project(false);
+        assert_eq!(app.current_project, 1);
+    }
+
+    #[test]
+    fn test_cycle_project_with_tag_filter() {
+        let mut app = create_test_app();
+        let mut second = Project::new("Second".to_string());
+        second.board.columns[0]
+            .tasks
+            .push(Task::new("Hidden".to_string()));
+        let mut shown = Task::new("Shown".to_string());
+        shown.add_tag("bug".to_string());
+        second.board.columns[0].tasks.push(shown);
+        app.projects.push(second);
+        app.active_tag_filter = Some("bug".to_string());
+
+        // the selection skips the first card, which the filter hides
+        app.cycle_project(true);
+        assert_eq!(app.current_project, 1);
+        assert_eq!(app.selected_index, 1);
+        assert_eq!(app.selected_task().unwrap().title, "Shown");
+    }
+
+    #[test]
+    fn test_due_dates_and_upcoming() {
+        let mut app = create_test_app();
+        app.board_mut().columns[1]
+            .tasks
+            .push(Task::new("Later".to_string()));
+
+        // second task of the first column is due first
+        app.selected_index = 1;
+        app.open_task();
+        app.start_editing_due_date();
+        app.set_input("2026-2-30".to_string());
+        app.submit_input();
+        assert_eq!(app.input_mode, InputMode::EditingDueDate);
+        app.set_input("2026-3-1".to_string());
+        app.submit_input();
+        assert_eq!(
+            app.selected_task().unwrap().due_date.as_deref(),
+            Some("2026-03-01")
+        );
+        app.close_view();
+        app.board_mut().columns[1].tasks[0].due_date = Some("2026-05-01".to_string());
+
+        assert_eq!(app.upcoming_tasks(), [(0, 1), (1, 0), (0, 0)]);
+
+        app.selected_index = 0;
+        app.open_upcoming();
+        app.move_upcoming_down();
+        app.jump_to_upcoming();
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert_eq!((app.selected_column, app.selected_index), (1, 0));
+    }
+
+    #[test]
+    fn test_delete_word_and_line() {
+        let mut app = create_test_app();
+        app.set_input("fix the  café bug  ".to_string());
+        app.delete_word_before_cursor();
+        assert_eq!(app.input_buffer, "fix the  café ");
+        app.delete_word_before_cursor();
+        assert_eq!(app.input_buffer, "fix the  ");
+
+        // stops at the cursor, keeping the text after it
+        app.set_input("one two three".to_string());
+        app.input_cursor = 7;
+        app.delete_word_before_cursor();
+        assert_eq!(app.input_buffer, "one  three");
+        assert_eq!(app.input_cursor, 4);
+
+        app.set_input("first\nsecond line\nthird".to_string());
+        app.cursor_up();
+        app.clear_input_line();
+        assert_eq!(app.input_buffer, "first\n\nthird");
+        assert_eq!(app.cursor_position(), (1, 0));
+
+        app.set_input("single line".to_string());
+        app.clear_input_line();
+        assert_eq!(app.input_buffer, "");
+    }
+
+    #[test]
+    fn test_multiline_cursor() {
+        let mut app = create_test_app();
+        app.board_mut().columns[0].tasks[0].description = "first\nsecond".to_string();
+        app.open_task();
+        app.start_editing_description();
+        assert_eq!(app.cursor_position(), (1, 6));
+
+        // up keeps the column where it fits, then clamps to the shorter line
+        app.cursor_left();
+        app.cursor_up();
+        assert_eq!(app.cursor_position(), (0, 5));
+        app.cursor_line_start();
+        app.input_char('a');
+        assert_eq!(app.input_buffer, "afirst\nsecond");
+
+        // backspace at the start of a line joins it with the previous one
+        app.cursor_down();
+        app.cursor_line_start();
+        app.input_backspace();
+        assert_eq!(app.input_buffer, "afirstsecond");
+        assert_eq!(app.cursor_position(), (0, 6));
+
+        app.input_delete();
+        assert_eq!(app.input_buffer, "afirstecond");
+    }
+
+    #[test]
+    fn test_move_task_to_project() {
+        let mut app = create_test_app();
+        app.projects.push(Project::new("Other".to_string()));
+
+        app.selected_index = 1;
+        app.open_task();
+        app.start_moving_task_to_project();
+        assert_eq!(app.input_mode, InputMode::MovingTaskToProject);
+        app.move_project_down();
+        app.move_task_to_project();
+
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert_eq!(app.board().columns[0].tasks.len(), 1);
+        assert_eq!(app.selected_index, 0);
+        let todo = &app.projects[1].board.columns[0];
+        assert_eq!(todo.id, "todo");
+        assert_eq!(todo.tasks[0].title, "Task 2");
+    }
+
+    #[test]
+    fn test_move_to_top_and_bottom() {
+        let mut app = create_test_app();
+        app.move_to_bottom();
+        assert_eq!(app.selected_index, 1);
+        app.move_to_top();
+        assert_eq!(app.selected_index, 0);
+
+        // empty column is a no-op
+        app.move_right();
+        app.move_to_bottom();
+        assert_eq!(app.selected_index, 0);
+    }
+
+    #[test]
+    fn test_pending_count() {
+        let mut app = create_test_app();
+        assert_eq!(app.take_count(), 1);
+
+        app.push_count_digit(1);
+        app.push_count_digit(2);
+        assert_eq!(app.pending_count, Some(12));
+        assert_eq!(app.take_count(), 12);
+        assert_eq!(app.pending_count, None);
+
+        for _ in 0..5 {
+            app.push_count_digit(9);
+        }
+        assert_eq!(app.take_count(), 999);
+    }
+
+    #[test]
+    fn test_open_project_named() {
+        let mut app = create_test_app();
+        app.projects.push(Project::new("Repo".to_string()));
+
+        app.open_project_named("Repo");
+        assert_eq!(app.current_project, 1);
+
+        // unknown names create the project
+        app.open_project_named("New Repo");
+        assert_eq!(app.projects.len(), 3);
+        assert_eq!(app.project_name(), "New Repo");
+
+        // other cases open the same project instead of adding a second one
+        app.open_project_named("repo");
+        assert_eq!(app.projects.len(), 3);
+        assert_eq!(app.project_name(), "Repo");
+    }
+
+    #[test]
+    fn test_duplicate_project_names_stay_unique() {
+        let mut app = create_test_app();
+        app.selected_project_index = 0;
+        app.duplicate_project();
+        app.selected_project_index = 0;
+        app.duplicate_project();
+        app.selected_project_index = 0;
+        app.duplicate_project();
+        let names: Vec<&str> = app.projects.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "Test Project",
+                "Test Project (copy)",
+                "Test Project (copy 2)",
+                "Test Project (copy 3)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_flash_expires() {
+        let mut app = create_test_app();
+        app.flash("Saved");
+        assert_eq!(app.flash_text(), Some("Saved"));
+
+        let old = Instant::now().checked_sub(FLASH_DURATION * 2).unwrap();
+        app.status_message = Some(("Saved".to_string(), old));
+        assert_eq!(app.flash_text(), None);
+        app.expire_flash();
+        assert!(app.status_message.is_none());
+    }
+
+    #[test]
+    fn test_cut_and_paste_task() {
+        let mut app = create_test_app();
+        app.cut_task();
+        assert_eq!(app.board().columns[0].tasks.len(), 1);
+        assert_eq!(app.clipboard.as_ref().unwrap().task.title, "Task 1");
+
+        // only one task fits in the clipboard
+        app.cut_task();
+        assert_eq!(app.board().columns[0].tasks.len(), 1);
+
+        app.move_right();
+        app.paste_task();
+        assert!(app.clipboard.is_none());
+        assert_eq!(app.board().columns[1].tasks[0].title, "Task 1");
+
+        // pasting lands before the selected task
+        app.cut_task();
+        app.move_left();
+        app.paste_task();
+        assert_eq!(app.board().columns[0].tasks[0].title, "Task 1");
+        assert_eq!(app.selected_index, 0);
+    }
+
+    #[test]
+    fn test_save_keeps_cut_task() {
+        let mut app = create_test_app();
+        assert!(app.projects_with_clipboard().is_none());
+
+        app.cut_task();
+        app.board_mut().columns[1].name = "Edited".to_string();
+        app.save();
+        app.flush_saves();
+
+        // the written copy still has the cut task, the board on screen doesn't
+        let saved = app.projects_with_clipboard().unwrap();
+        let titles: Vec<&str> = saved[0].board.columns[0]
+            .tasks
+            .iter()
+            .map(|task| task.title.as_str())
+            .collect();
+        assert_eq!(titles, ["Task 2", "Task 1"]);
+        assert_eq!(saved[0].board.columns[1].name, "Edited");
+        assert_eq!(app.board().columns[0].tasks.len(), 1);
+        assert!(app.clipboard.is_some());
+    }
+
+    #[test]
+    fn test_restore_clipboard() {
+        let mut app = create_test_app();
+        app.cut_task();
+        app.restore_clipboard();
+        assert!(app.clipboard.is_none());
+        assert_eq!(app.board().columns[0].tasks.len(), 2);
+    }
+
+    #[test]
+    fn test_sort_column_alphabetical() {
+        let mut app = create_test_app();
+        app.board_mut().columns[0].tasks[0].title = "banana".to_string();
+        app.board_mut().columns[0].tasks[1].title = "Apple".to_string();
+        app.board_mut().columns[0]
+            .tasks
+            .push(Task::new("cherry".to_string()));
+
+        app.sort_column_alphabetical();
+        let titles: Vec<&str> = app.board().columns[0]
+            .tasks
+            .iter()
+            .map(|t| t.title.as_str())
+            .collect();
+        assert_eq!(titles, vec!["Apple", "banana", "cherry"]);
+        assert_eq!(app.selected_index, 1); // still on "banana"
+
+        // empty column is a no-op
+        app.move_right();
+        app.sort_column_alphabetical();
+        assert!(app.board().columns[1].tasks.is_empty());
     }
 
-    pub fn select_project(&mut self) {
-        self.current_project = self.selected_project_index;
-        self.input_mode = InputMode::Normal;
-        self.selected_column = 0; // Reset to first column when changing projects
-        self.selected_index = 0;
-        self.scroll_offset = 0;
+    #[test]
+    fn test_tag_picker() {
+        let mut app = create_test_app();
+        app.board_mut().columns[0].tasks[0].add_tag("design".to_string());
+        app.start_adding_tag();
+        assert_eq!(
+            app.tag_suggestions().len(),
+            board::PREDEFINED_TAGS.len() - 1
+        );
+
+        // typing narrows the list, tags already on the task are left out
+        app.input_char('d');
+        assert_eq!(app.tag_suggestions(), vec!["dev", "documentation"]);
+        app.suggestion_down();
+        app.suggestion_down();
+        app.suggestion_down();
+        assert_eq!(app.highlighted_suggestion(), Some("documentation"));
+        app.submit_input();
+        assert_eq!(
+            app.board().columns[0].tasks[0].tags,
+            vec!["design", "documentation"]
+        );
+
+        // free form tags still work
+        app.start_adding_tag();
+        app.input_buffer = "fature".to_string();
+        app.suggestion_down();
+        app.submit_input();
+        assert!(app.board().columns[0].tasks[0].has_tag("fature"));
     }
 
-    pub fn move_project_up(&mut self) {
-        if self.selected_project_index > 0 {
-            self.selected_project_index -= 1;
+    #[test]
+    fn test_scroll_resets_when_column_shrinks() {
+        let mut app = create_test_app();
+        app.confirm_delete = false;
+        app.visible_items = 2;
+        for i in 0..4 {
+            app.board_mut().columns[0]
+                .tasks
+                .push(Task::new(format!("Extra {}", i)));
         }
-    }
+        app.move_to_bottom();
+        assert_eq!(app.scroll_offset, 4);
 
-    pub fn move_project_down(&mut self) {
-        if self.selected_project_index < self.projects.len() - 1 {
-            self.selected_project_index += 1;
+        // deleting down to fewer tasks than fit shows the column from the top
+        for _ in 0..5 {
+            app.delete_task();
         }
+        assert_eq!(app.board().columns[0].tasks.len(), 1);
+        assert_eq!(app.scroll_offset, 0);
+
+        // same when tasks move out
+        for i in 0..4 {
+            app.board_mut().columns[0]
+                .tasks
+                .push(Task::new(format!("More {}", i)));
+        }
+        app.move_to_bottom();
+        assert_eq!(app.scroll_offset, 3);
+        for _ in 0..4 {
+            app.move_task_forward();
+        }
+        assert_eq!(app.scroll_offset, 0);
     }
 
-    pub fn start_adding_project(&mut self) {
-        self.input_mode = InputMode::AddingProject;
-        self.input_buffer.clear();
+    #[test]
+    fn test_rename_tag_everywhere() {
+        let mut app = create_test_app();
+        app.board_mut().columns[0].tasks[0].add_tag("wip".to_string());
+        app.board_mut().columns[0].tasks[1].add_tag("in-progress".to_string());
+        app.board_mut().columns[0].tasks[1].add_tag("wip".to_string());
+        app.board_mut().columns[1]
+            .tasks
+            .push(Task::new("Untagged".to_string()));
+
+        app.start_renaming_tag_everywhere();
+        app.input_buffer = "#wip".to_string();
+        app.submit_input();
+        assert_eq!(app.input_mode, InputMode::RenamingTagEverywhere);
+        app.input_buffer = "in-progress".to_string();
+        app.submit_input();
+
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert_eq!(app.board().columns[0].tasks[0].tags, vec!["in-progress"]);
+        // card that already had the new tag is deduped
+        assert_eq!(app.board().columns[0].tasks[1].tags, vec!["in-progress"]);
+        assert_eq!(
+            app.flash_text(),
+            Some("Renamed #wip to #in-progress on 2 cards")
+        );
     }
 
-    pub fn delete_project(&mut self) {
-        if self.projects.len() > 1 {
-            self.projects.remove(self.selected_project_index);
-            if self.selected_project_index >= self.projects.len() {
-                self.selected_project_index = self.projects.len() - 1;
-            }
-            if self.current_project >= self.projects.len() {
-                self.current_project = self.projects.len() - 1;
-            }
-            self.save();
+    #[test]
+    fn test_tag_and_untag_whole_column() {
+        let mut app = create_test_app();
+        app.board_mut().columns[0].tasks[1].add_tag("review".to_string());
+
+        app.start_tagging_column(false);
+        assert_eq!(app.input_mode, InputMode::TaggingColumn);
+        app.input_buffer = "#Review".to_string();
+        app.submit_input();
+        assert_eq!(app.flash_text(), Some("Tagged 1 card with #review"));
+        for task in &app.board().columns[0].tasks {
+            assert_eq!(task.tags, vec!["review"]);
         }
+
+        app.start_tagging_column(true);
+        app.input_buffer = "review".to_string();
+        app.submit_input();
+        assert_eq!(app.flash_text(), Some("Removed #review from 2 cards"));
+        assert!(app.board().columns[0]
+            .tasks
+            .iter()
+            .all(|task| task.tags.is_empty()));
+
+        // nothing to tag in an empty column
+        app.selected_column = 1;
+        app.start_tagging_column(false);
+        assert_eq!(app.input_mode, InputMode::Normal);
     }
 
-    // show help view
-    pub fn show_help(&mut self) {
-        self.input_mode = InputMode::ViewingHelp;
+    #[test]
+    fn test_navigation_without_columns() {
+        let mut project = Project::new("Empty".to_string());
+        project.board.columns.clear();
+        let mut app = App::new_with_projects(vec![project]);
+
+        app.move_right();
+        app.move_left();
+        app.move_down();
+        app.move_up();
+        app.update_scroll();
+        assert_eq!(app.selected_column, 0);
+        assert_eq!(app.selected_index, 0);
+
+        app.board_mut().ensure_column();
+        assert_eq!(app.board().columns.len(), 1);
     }
 
-    // close detail/help view
-    pub fn close_view(&mut self) {
-        self.input_mode = InputMode::Normal;
-        self.input_buffer.clear();
+    #[test]
+    fn test_delete_non_empty_column() {
+        let mut app = create_test_app();
+        app.delete_column();
+        assert_eq!(app.input_mode, InputMode::ConfirmDelete);
+        assert_eq!(
+            app.delete_prompt().as_deref(),
+            Some("Delete column \"Column 1\" and its 2 tasks? (y/n)")
+        );
+
+        app.cancel_pending_delete();
+        assert_eq!(app.board().columns.len(), 2);
+
+        app.delete_column();
+        app.confirm_pending_delete();
+        assert_eq!(app.board().columns.len(), 1);
+        assert_eq!(app.board().columns[0].name, "Column 2");
+
+        // the last column stays
+        app.delete_column();
+        assert_eq!(app.board().columns.len(), 1);
+        assert_eq!(app.input_mode, InputMode::Normal);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::board::{Board, BoardColumn, Project, Task};
+    #[test]
+    fn test_remove_selected_tag_beyond_nine() {
+        let mut app = create_test_app();
+        for i in 0..12 {
+            app.selected_task_mut()
+                .unwrap()
+                .add_tag(format!("tag{}", i));
+        }
+        app.open_task();
+        for _ in 0..11 {
+            app.move_tag_down();
+        }
+        app.remove_selected_tag();
+        let tags = &app.selected_task().unwrap().tags;
+        assert_eq!(tags.len(), 11);
+        assert!(!tags.contains(&"tag11".to_string()));
+        // the highlight moves to the new last tag
+        assert_eq!(app.selected_tag_index, 10);
+    }
 
-    fn create_test_app() -> App {
-        let board = Board {
-            columns: vec![
-                BoardColumn {
-                    id: "col1".to_string(),
-                    name: "Column 1".to_string(),
-                    tasks: vec![
-                        Task::new("Task 1".to_string()),
-                        Task::new("Task 2".to_string()),
-                    ],
-                },
-                BoardColumn {
-                    id: "col2".to_string(),
-                    name: "Column 2".to_string(),
-                    tasks: vec![],
-                },
-            ],
-        };
-        let project = Project {
-            name: "Test Project".to_string(),
-            board,
-        };
-        App::new_with_projects(vec![project])
+    #[test]
+    fn test_edit_tag() {
+        let mut app = create_test_app();
+        app.board_mut().columns[0].tasks[0].add_tag("bgu".to_string());
+        app.board_mut().columns[0].tasks[0].add_tag("urgent".to_string());
+        app.open_task();
+        app.next_field();
+
+        app.start_editing_tag();
+        assert_eq!(app.input_mode, InputMode::EditingTag);
+        assert_eq!(app.input_buffer, "bgu");
+        app.input_buffer = "bug".to_string();
+        app.submit_input();
+        assert_eq!(app.input_mode, InputMode::ViewingTask);
+        assert_eq!(app.board().columns[0].tasks[0].tags, vec!["bug", "urgent"]);
+
+        // Renaming to an existing tag is rejected
+        app.move_tag_down();
+        app.start_editing_tag();
+        app.input_buffer = "bug".to_string();
+        app.submit_input();
+        assert_eq!(app.board().columns[0].tasks[0].tags, vec!["bug", "urgent"]);
     }
 
     #[test]
@@ -600,6 +3731,64 @@ mod tests {
         assert_eq!(app.selected_column, 0);
     }
 
+    #[test]
+    fn test_tag_filter_navigation() {
+        let mut app = create_test_app();
+        app.board_mut().columns[0]
+            .tasks
+            .push(Task::new("Task 3".to_string()));
+        app.board_mut().columns[0].tasks[0].add_tag("bug".to_string());
+        app.board_mut().columns[0].tasks[2].add_tag("bug".to_string());
+
+        app.input_mode = InputMode::FilteringTag;
+        app.input_buffer = "#bug".to_string();
+        app.submit_input();
+        assert_eq!(app.active_tag_filter.as_deref(), Some("bug"));
+
+        // Task 2 is hidden, so moving down skips it
+        app.move_down();
+        assert_eq!(app.selected_index, 2);
+        app.move_up();
+        assert_eq!(app.selected_index, 0);
+
+        // Column 2 has no matching tasks, so there is nothing to act on
+        app.move_right();
+        app.move_task_forward();
+        app.move_left();
+        assert_eq!(app.board().columns[0].tasks.len(), 3);
+
+        // Second press clears the filter
+        app.toggle_tag_filter();
+        assert!(app.active_tag_filter.is_none());
+        app.move_down();
+        assert_eq!(app.selected_index, 1);
+    }
+
+    #[test]
+    fn test_restore_session_clamps() {
+        let mut app = create_test_app();
+
+        app.restore_session(&Session {
+            current_project: 0,
+            selected_column: 0,
+            selected_index: 1,
+            scroll_offset: 0,
+        });
+        assert_eq!(app.selected_index, 1);
+
+        // Out of range positions from an older board get clamped
+        app.restore_session(&Session {
+            current_project: 7,
+            selected_column: 9,
+            selected_index: 4,
+            scroll_offset: 3,
+        });
+        assert_eq!(app.current_project, 0);
+        assert_eq!(app.selected_column, 1);
+        assert_eq!(app.selected_index, 0);
+        assert_eq!(app.scroll_offset, 0);
+    }
+
     #[test]
     fn test_task_movement() {
         let mut app = create_test_app();
@@ -626,15 +3815,107 @@ mod tests {
         assert_eq!(app.board().columns[1].tasks.len(), 2);
     }
 
+    #[test]
+    fn test_move_task_to_column() {
+        let mut app = create_test_app();
+
+        // Out of range column is a no-op
+        app.move_task_to_column(5);
+        assert_eq!(app.board().columns[0].tasks.len(), 2);
+
+        app.selected_index = 1;
+        app.move_task_to_column(1);
+        assert_eq!(app.board().columns[0].tasks.len(), 1);
+        assert_eq!(app.board().columns[1].tasks[0].title, "Task 2");
+        assert_eq!(app.selected_index, 0); // Clamped to remaining task
+    }
+
+    #[test]
+    fn test_archive_and_restore() {
+        let mut app = create_test_app();
+
+        // Column 1 isn't the done column, so nothing is archived
+        app.archive_task();
+        assert!(app.archived().is_empty());
+
+        app.move_task_forward();
+        app.move_right();
+        app.archive_task();
+        assert!(app.board().columns[1].tasks.is_empty());
+        assert_eq!(app.archived().len(), 1);
+        assert_eq!(app.archived()[0].title, "Task 1");
+
+        // Restore into the selected column
+        app.move_left();
+        app.open_archive();
+        app.restore_archived_task();
+        assert!(app.archived().is_empty());
+        assert_eq!(app.board().columns[0].tasks.len(), 2);
+        assert_eq!(app.board().columns[0].tasks[1].title, "Task 1");
+    }
+
+    #[test]
+    fn test_blocked_task_stays_in_first_column() {
+        let mut app = create_test_app();
+
+        // Block Task 1 on Task 2
+        app.open_task();
+        app.start_picking_blocker();
+        assert_eq!(app.blocker_candidates(), vec![(0, 1)]);
+        app.toggle_blocker();
+        app.close_view();
+
+        app.move_task_forward();
+        assert_eq!(app.board().columns[0].tasks.len(), 2);
+        assert!(app.status_message.is_some());
+
+        // Unblock and move
+        app.open_task();
+        app.start_picking_blocker();
+        app.toggle_blocker();
+        app.close_view();
+        app.move_task_forward();
+        assert_eq!(app.board().columns[1].tasks[0].title, "Task 1");
+    }
+
     #[test]
     fn test_delete_task() {
         let mut app = create_test_app();
 
         app.delete_task();
+        assert_eq!(app.input_mode, InputMode::ConfirmDelete);
+        assert_eq!(app.board().columns[0].tasks.len(), 2); // Nothing deleted yet
+
+        app.confirm_pending_delete();
+        assert_eq!(app.input_mode, InputMode::Normal);
         assert_eq!(app.board().columns[0].tasks.len(), 1);
         assert_eq!(app.board().columns[0].tasks[0].title, "Task 2");
     }
 
+    #[test]
+    fn test_delete_task_cancel() {
+        let mut app = create_test_app();
+
+        app.delete_task();
+        assert_eq!(app.delete_prompt().unwrap(), "Delete \"Task 1\"? (y/n)");
+
+        app.cancel_pending_delete();
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert!(app.pending_delete.is_none());
+        assert_eq!(app.board().columns[0].tasks.len(), 2);
+    }
+
+    #[test]
+    fn test_delete_task_without_confirmation() {
+        let mut app = create_test_app();
+        app.toggle_confirm_delete();
+        assert!(!app.settings.confirm_delete);
+
+        app.delete_task();
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert_eq!(app.board().columns[0].tasks.len(), 1);
+    }
+
     #[test]
     fn test_add_column() {
         let mut app = create_test_app();
@@ -647,6 +3928,22 @@ mod tests {
         assert_eq!(app.board().columns[2].name, "Column 3");
     }
 
+    #[test]
+    fn test_add_column_ids_stay_unique() {
+        let mut app = create_test_app();
+        for name in ["In Review", "in_review", "IN REVIEW"] {
+            app.input_buffer = name.to_string();
+            app.input_mode = InputMode::AddingColumn;
+            app.submit_input();
+        }
+
+        // the last one only differs in case from the first and is refused
+        assert_eq!(app.board().columns.len(), 4);
+        assert!(app.flash_text().unwrap().contains("already"));
+        assert_eq!(app.board().columns[2].id, "in_review");
+        assert_eq!(app.board().columns[3].id, "in_review_2");
+    }
+
     #[test]
     fn test_rename_column() {
         let mut app = create_test_app();
@@ -659,13 +3956,40 @@ mod tests {
         assert_eq!(app.board().columns[0].name, "Renamed 1");
     }
 
+    #[test]
+    fn test_rename_column_refuses_duplicates() {
+        let mut app = create_test_app();
+        app.selected_column = 0;
+        app.submit_as(InputMode::RenamingColumn, " column 2 ".to_string());
+        assert_eq!(app.board().columns[0].name, "Column 1");
+        assert!(app.flash_text().is_some());
+
+        // blank names are ignored, also from the command line
+        app.run_command(Command::ColumnRename("   ".to_string()));
+        assert_eq!(app.board().columns[0].name, "Column 1");
+        app.run_command(Command::ColumnRename("Column 2".to_string()));
+        assert_eq!(app.board().columns[0].name, "Column 1");
+
+        // changing the case of its own name is fine, and the input is trimmed
+        app.submit_as(InputMode::RenamingColumn, " COLUMN 1 ".to_string());
+        assert_eq!(app.board().columns[0].name, "COLUMN 1");
+    }
+
     #[test]
     fn test_delete_column() {
         let mut app = create_test_app();
 
-        // Cannot delete non-empty column (simplified logic check)
+        // non-empty column asks first; saying no keeps it
         app.selected_column = 0;
         app.delete_column();
+        assert_eq!(app.input_mode, InputMode::ConfirmDelete);
+        assert_eq!(
+            app.delete_prompt().as_deref(),
+            Some("Delete column \"Column 1\" and its 2 tasks? (y/n)")
+        );
+        app.cancel_pending_delete();
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert!(app.pending_delete.is_none());
         assert_eq!(app.board().columns.len(), 2); // Should still be 2
 
         // Delete empty column (Col 2)
@@ -674,18 +3998,154 @@ mod tests {
         assert_eq!(app.board().columns.len(), 1);
         assert_eq!(app.board().columns[0].name, "Column 1");
 
-        // Cannot delete last remaining column
-        app.delete_column(); // Even if empty (it's not here, but let's clear it)
+        // Cannot delete last remaining column, not even to ask
+        app.delete_column();
+        assert_eq!(app.input_mode, InputMode::Normal);
 
         // Clear tasks to try deleting last column
         app.delete_task();
+        app.confirm_pending_delete();
         app.delete_task();
+        app.confirm_pending_delete();
         assert!(app.board().columns[0].tasks.is_empty());
 
         app.delete_column();
         assert_eq!(app.board().columns.len(), 1); // Should guard against deleting the last column
     }
 
+    #[test]
+    fn test_clear_column() {
+        let mut app = create_test_app();
+        app.selected_index = 1;
+
+        app.clear_column();
+        assert_eq!(app.input_mode, InputMode::ConfirmDelete);
+        assert_eq!(
+            app.delete_prompt().as_deref(),
+            Some("Clear all 2 tasks in \"Column 1\"? (y/n)")
+        );
+        app.cancel_pending_delete();
+        assert_eq!(app.board().columns[0].tasks.len(), 2);
+
+        app.clear_column();
+        app.confirm_pending_delete();
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert!(app.board().columns[0].tasks.is_empty());
+        assert_eq!(app.board().columns.len(), 2); // the column itself stays
+        assert_eq!(app.selected_index, 0);
+
+        // nothing to ask about for an empty column
+        app.clear_column();
+        assert_eq!(app.input_mode, InputMode::Normal);
+    }
+
+    #[test]
+    fn test_activity_log() {
+        let mut app = create_test_app();
+        app.submit_as(InputMode::AddingTask, "Write docs".to_string());
+        app.move_task_forward();
+        app.selected_column = 1;
+        app.selected_index = 0;
+        app.input_mode = InputMode::AddingTag;
+        app.set_input("docs".to_string());
+        app.submit_input();
+        app.confirm_delete = false;
+        app.delete_task();
+
+        let messages: Vec<&str> = app.projects[0]
+            .log
+            .iter()
+            .map(|entry| entry.message.as_str())
+            .collect();
+        assert_eq!(
+            messages,
+            [
+                "Added \"Write docs\" to Column 1",
+                "Moved \"Write docs\" to Column 2",
+                "Tagged \"Write docs\" with #docs",
+                "Deleted \"Write docs\"",
+            ]
+        );
+
+        app.open_activity_log();
+        assert_eq!(app.input_mode, InputMode::ActivityLog);
+        app.scroll_log_down(10); // clamped by what was last drawn
+        assert_eq!(app.log_scroll, 0);
+    }
+
+    #[test]
+    fn test_activity_log_skips_unchanged_tags() {
+        let mut app = create_test_app();
+        let tag = |app: &mut App, text: &str| {
+            app.input_mode = InputMode::AddingTag;
+            app.set_input(text.to_string());
+            app.submit_input();
+        };
+        tag(&mut app, " #Docs");
+        assert_eq!(app.projects[0].log.len(), 1);
+        assert_eq!(
+            app.projects[0].log[0].message,
+            "Tagged \"Task 1\" with #docs"
+        );
+
+        // already there, or nothing left after normalizing: no log entry
+        tag(&mut app, "docs");
+        tag(&mut app, "#");
+        assert_eq!(app.projects[0].log.len(), 1);
+        assert_eq!(app.board().columns[0].tasks[0].tags, ["docs"]);
+    }
+
+    #[test]
+    fn test_backlog_column() {
+        let mut app = create_test_app();
+        app.pull_from_backlog();
+        assert_eq!(
+            app.flash_text(),
+            Some("No backlog column (Shift+B marks one)")
+        );
+
+        // mark column 1 as the backlog, pulling moves its top card to column 2
+        app.toggle_backlog_column();
+        assert!(app.board().columns[0].backlog);
+        assert!(app.board().columns[0].collapsed);
+        app.pull_from_backlog();
+        assert_eq!(app.flash_text(), Some("Pulled \"Task 1\" into Column 2"));
+        assert_eq!(app.board().columns[1].tasks[0].title, "Task 1");
+        assert_eq!(app.board().columns[0].tasks.len(), 1);
+
+        // only one backlog per board
+        app.selected_column = 1;
+        app.toggle_backlog_column();
+        assert!(!app.board().columns[0].backlog);
+        assert!(app.board().columns[1].backlog);
+        app.toggle_backlog_column();
+        assert_eq!(app.board().backlog_index(), None);
+    }
+
+    #[test]
+    fn test_sticky_add() {
+        let mut app = create_test_app();
+        app.start_adding_tasks();
+        for title in ["One", "Two", "Three"] {
+            app.input_buffer = title.to_string();
+            app.submit_input();
+            assert_eq!(app.input_mode, InputMode::AddingTask);
+            assert!(app.input_buffer.is_empty());
+        }
+        assert_eq!(app.board().columns[0].tasks.len(), 5);
+        assert_eq!(app.selected_task().unwrap().title, "Three");
+
+        app.cancel_input();
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert!(!app.sticky_add);
+
+        // a plain add still closes the prompt
+        app.start_adding_task();
+        app.input_buffer = "Four".to_string();
+        app.submit_input();
+        assert_eq!(app.input_mode, InputMode::Normal);
+    }
+
     #[test]
     fn test_move_column() {
         let mut app = create_test_app();
@@ -1,19 +1,201 @@
+use crate::datetime::{self, Timestamp};
 use ratatui::style::Color;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::RwLock;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// user defined tag colors, consulted before the built-in table
+static CUSTOM_TAG_COLORS: RwLock<Vec<(String, Color)>> = RwLock::new(Vec::new());
+
+// (id, name) of the columns new boards start with, from settings.json (built-in when empty)
+static DEFAULT_COLUMNS: RwLock<Vec<(String, String)>> = RwLock::new(Vec::new());
+
+// generate a unique task id (time based with a counter for tasks made in the same instant)
+pub fn new_task_id() -> String {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_nanos() as u64);
+    let count = COUNTER.fetch_add(1, Ordering::Relaxed);
+    format!("{:x}-{:x}", nanos, count)
+}
+
+// parse a named ratatui color ("red", "lightblue") or hex "#rrggbb"
+pub fn parse_color(s: &str) -> Option<Color> {
+    s.trim().parse::<Color>().ok()
+}
+
+// replace the user defined tag colors, skipping invalid color strings
+pub fn set_custom_tag_colors(colors: &HashMap<String, String>) {
+    let parsed = colors
+        .iter()
+        .filter_map(|(tag, color)| parse_color(color).map(|c| (normalize_tag(tag), c)))
+        .collect();
+    if let Ok(mut custom) = CUSTOM_TAG_COLORS.write() {
+        *custom = parsed;
+    }
+}
+
+// set the columns new boards start with (the settings validate them first)
+pub fn set_default_columns(columns: &[(String, String)]) {
+    if let Ok(mut defaults) = DEFAULT_COLUMNS.write() {
+        *defaults = columns.to_vec();
+    }
+}
+
+// (id, name) of the columns of a template, the first one being the configured default
+pub fn template_columns(template: usize) -> Vec<(String, String)> {
+    if template == 0 {
+        if let Some(columns) = DEFAULT_COLUMNS
+            .read()
+            .ok()
+            .filter(|columns| !columns.is_empty())
+        {
+            return columns.clone();
+        }
+    }
+    builtin_template_columns(template)
+}
+
+// (id, name) of the columns of a template as shipped, ignoring default_columns
+pub fn builtin_template_columns(template: usize) -> Vec<(String, String)> {
+    let (_, columns) = COLUMN_TEMPLATES
+        .get(template)
+        .unwrap_or(&COLUMN_TEMPLATES[0]);
+    columns
+        .iter()
+        .map(|(id, name)| (id.to_string(), name.to_string()))
+        .collect()
+}
+
+// tags with a built-in color: name, color and the help screen description
+pub const PREDEFINED_TAGS: &[(&str, Color, &str)] = &[
+    ("urgent", Color::Red, "Red - High priority"),
+    ("security", Color::LightRed, "Light Red - Security work"),
+    ("bug", Color::Yellow, "Yellow - Needs fixing"),
+    ("feature", Color::Green, "Green - New feature"),
+    (
+        "performance",
+        Color::LightGreen,
+        "Light Green - Optimization",
+    ),
+    ("enhancement", Color::Blue, "Blue - Improvement"),
+    ("user", Color::LightBlue, "Light Blue - User-facing"),
+    ("dev", Color::Magenta, "Magenta - Developer work"),
+    ("documentation", Color::Cyan, "Cyan - Documentation"),
+    ("design", Color::LightCyan, "Light Cyan - UI/UX work"),
+    (
+        "refactor",
+        Color::LightYellow,
+        "Light Yellow - Code quality",
+    ),
+];
+
+// parse an estimate in hours like "3", "1.5" or "2h", empty clears it
+pub fn parse_estimate(input: &str) -> Result<Option<f32>, String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Ok(None);
+    }
+    let number = input.strip_suffix('h').unwrap_or(input).trim();
+    match number.parse::<f32>() {
+        Ok(hours) if hours.is_finite() && hours >= 0.0 => Ok(Some(hours)),
+        _ => Err(format!("\"{}\" is not a number of hours", input)),
+    }
+}
+
+// hours as shown on cards, "3h" or "1.5h"
+pub fn format_estimate(hours: f32) -> String {
+    let rounded = (hours * 10.0).round() / 10.0;
+    format!("{}h", rounded)
+}
+
+// relative column widths: columns start equal at the default weight, and can be made
+// between half and three times as wide
+const DEFAULT_COLUMN_WEIGHT: u16 = 4;
+const MIN_COLUMN_WEIGHT: u16 = 2;
+const MAX_COLUMN_WEIGHT: u16 = 12;
+
+// column layouts to pick from when creating a project, as (name, [(id, column name)]),
+// the first one is the built-in default that settings.json can replace
+pub const COLUMN_TEMPLATES: &[(&str, &[(&str, &str)])] = &[
+    (
+        "Default",
+        &[
+            ("todo", "To Do"),
+            ("in_progress", "In Progress"),
+            ("testing", "Testing"),
+            ("done", "Done"),
+        ],
+    ),
+    ("Simple", &[("todo", "To Do"), ("done", "Done")]),
+    (
+        "Scrum",
+        &[
+            ("backlog", "Backlog"),
+            ("sprint", "Sprint"),
+            ("doing", "Doing"),
+            ("review", "Review"),
+            ("done", "Done"),
+        ],
+    ),
+];
+
+// colors a card can be flagged with from the detail view, in cycle order
+pub const TASK_COLOR_PALETTE: &[&str] = &["red", "yellow", "green", "cyan", "blue", "magenta"];
+
+// stored form of a tag: trimmed, lowercase, without a leading '#'
+pub fn normalize_tag(tag: &str) -> String {
+    tag.trim().trim_start_matches('#').trim().to_lowercase()
+}
 
 // simple task with title, tags, and description
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Task {
+    #[serde(default = "new_task_id")]
+    pub id: String,
     pub title: String,
     pub tags: Vec<String>,
     pub description: String,
+    #[serde(default)]
+    pub created_at: Option<Timestamp>, // None for tasks saved before timestamps existed
+    #[serde(default)]
+    pub updated_at: Option<Timestamp>,
+    #[serde(default)]
+    pub blocked_by: Vec<String>, // ids of tasks that must be done first
+    #[serde(default)]
+    pub color: Option<String>, // manual card color, wins over column/theme colors
+    #[serde(default)]
+    pub estimate: Option<f32>, // rough effort in hours
+    #[serde(default)]
+    pub due_date: Option<String>, // "YYYY-MM-DD", so dates sort as strings
+    #[serde(default)]
+    pub order: u32, // position in its column, renumbered on every save
 }
 
-// project contains a name and a board
+// project contains a name, a board and archived tasks
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Project {
     pub name: String,
     pub board: Board,
+    #[serde(default)]
+    pub archived: Vec<Task>,
+    #[serde(default)]
+    pub color: Option<String>, // named color or #rrggbb for the project name
+    #[serde(default)]
+    pub log: Vec<LogEntry>, // activity, oldest first, at most MAX_LOG_ENTRIES
+}
+
+// entries kept in a project's activity log, the oldest are dropped first
+pub const MAX_LOG_ENTRIES: usize = 500;
+
+// one line of the activity log, like "Moved \"Fix login\" to Done"
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct LogEntry {
+    pub at: Timestamp,
+    pub message: String,
 }
 
 impl Project {
@@ -21,43 +203,204 @@ impl Project {
         Self {
             name,
             board: Board::new(),
+            archived: Vec::new(),
+            color: None,
+            log: Vec::new(),
+        }
+    }
+
+    // new project with the columns of one of COLUMN_TEMPLATES (the default one if out of range)
+    pub fn new_from_template(name: String, template: usize) -> Self {
+        Self {
+            name,
+            board: Board::from_columns(&template_columns(template)),
+            archived: Vec::new(),
+            color: None,
+            log: Vec::new(),
         }
     }
+
+    // add a line to the activity log, dropping the oldest past MAX_LOG_ENTRIES
+    pub fn record(&mut self, message: impl Into<String>) {
+        self.log.push(LogEntry {
+            at: datetime::now(),
+            message: message.into(),
+        });
+        let excess = self.log.len().saturating_sub(MAX_LOG_ENTRIES);
+        self.log.drain(..excess);
+    }
+
+    // the project name color, if set and valid
+    pub fn accent(&self) -> Option<Color> {
+        self.color.as_deref().and_then(parse_color)
+    }
+
+    // move done tasks last updated before the cutoff into the archive, returns how many
+    pub fn archive_done_before(&mut self, cutoff: Timestamp) -> usize {
+        let Some(done_idx) = self.board.done_column_index() else {
+            return 0;
+        };
+        let tasks = std::mem::take(&mut self.board.columns[done_idx].tasks);
+        let (old, keep): (Vec<Task>, Vec<Task>) = tasks
+            .into_iter()
+            .partition(|task| task.updated_at.is_some_and(|at| at < cutoff));
+        self.board.columns[done_idx].tasks = keep;
+        let archived = old.len();
+        self.archived.extend(old);
+        archived
+    }
+
+    // deep copy named "<name> (copy)" with fresh task ids, blockers pointing at the copies
+    pub fn duplicate(&self) -> Self {
+        let mut copy = self.clone();
+        copy.name = format!("{} (copy)", self.name);
+        copy.log.clear();
+
+        let mut new_ids = HashMap::new();
+        let tasks = copy
+            .board
+            .columns
+            .iter_mut()
+            .flat_map(|col| col.tasks.iter_mut())
+            .chain(copy.archived.iter_mut());
+        for task in tasks {
+            let id = new_task_id();
+            new_ids.insert(std::mem::replace(&mut task.id, id.clone()), id);
+        }
+
+        let tasks = copy
+            .board
+            .columns
+            .iter_mut()
+            .flat_map(|col| col.tasks.iter_mut())
+            .chain(copy.archived.iter_mut());
+        for task in tasks {
+            for blocker in &mut task.blocked_by {
+                if let Some(id) = new_ids.get(blocker) {
+                    *blocker = id.clone();
+                }
+            }
+        }
+        copy
+    }
 }
 
 impl Task {
     // Create task
     pub fn new(title: String) -> Self {
+        let now = datetime::now();
         Self {
+            id: new_task_id(),
             title,
             tags: Vec::new(),
             description: String::new(),
+            created_at: Some(now),
+            updated_at: Some(now),
+            blocked_by: Vec::new(),
+            color: None,
+            estimate: None,
+            due_date: None,
+            order: 0,
         }
     }
 
-    // add tags to the task
-    pub fn add_tag(&mut self, tag: String) {
-        if !self.tags.contains(&tag) {
-            self.tags.push(tag);
+    // step to the next card color in the palette, wrapping back to no color
+    pub fn cycle_color(&mut self) {
+        let next = match &self.color {
+            None => Some(0),
+            Some(current) => TASK_COLOR_PALETTE
+                .iter()
+                .position(|c| c == current)
+                .map(|i| i + 1)
+                .filter(|&i| i < TASK_COLOR_PALETTE.len()),
+        };
+        self.color = next.map(|i| TASK_COLOR_PALETTE[i].to_string());
+        self.touch();
+    }
+
+    // the manual card color, if set and valid
+    pub fn accent(&self) -> Option<Color> {
+        self.color.as_deref().and_then(parse_color)
+    }
+
+    // mark the task as modified now
+    pub fn touch(&mut self) {
+        self.updated_at = Some(datetime::now());
+    }
+
+    // check for a tag, ignoring case and '#' so older tags still match
+    pub fn has_tag(&self, tag: &str) -> bool {
+        let tag = normalize_tag(tag);
+        self.tags.iter().any(|t| normalize_tag(t) == tag)
+    }
+
+    // add tags to the task (normalized, duplicates and empty tags ignored)
+    // add a tag unless it is empty or already there, returns whether it was added
+    pub fn add_tag(&mut self, tag: String) -> bool {
+        let tag = normalize_tag(&tag);
+        if tag.is_empty() || self.has_tag(&tag) {
+            return false;
+        }
+        self.tags.push(tag);
+        self.touch();
+        true
+    }
+
+    // replace the tag at index in place, ignoring duplicates like add_tag
+    pub fn rename_tag(&mut self, index: usize, tag: String) -> bool {
+        let tag = normalize_tag(&tag);
+        let duplicate = self
+            .tags
+            .iter()
+            .enumerate()
+            .any(|(i, t)| i != index && normalize_tag(t) == tag);
+        if index >= self.tags.len() || tag.is_empty() || duplicate || self.tags[index] == tag {
+            return false;
+        }
+        self.tags[index] = tag;
+        self.touch();
+        true
+    }
+
+    // replace tag `from` with `to`, dropping it instead if `to` is already there
+    pub fn replace_tag(&mut self, from: &str, to: &str) -> bool {
+        let from = normalize_tag(from);
+        let Some(index) = self.tags.iter().position(|t| normalize_tag(t) == from) else {
+            return false;
+        };
+        if self.has_tag(to) {
+            self.tags.remove(index);
+            self.touch();
+            true
+        } else {
+            self.rename_tag(index, to.to_string())
         }
     }
 
-    // return color for a specific tag
+    // remove a tag by name, ignoring case and '#' like has_tag
+    pub fn remove_tag_named(&mut self, tag: &str) -> bool {
+        let tag = normalize_tag(tag);
+        let Some(index) = self.tags.iter().position(|t| normalize_tag(t) == tag) else {
+            return false;
+        };
+        self.tags.remove(index);
+        self.touch();
+        true
+    }
+
+    // return color for a specific tag (user colors first, then built-in)
     pub fn get_tag_color(tag: &str) -> Color {
-        match tag {
-            "urgent" => Color::Red,
-            "security" => Color::LightRed,
-            "bug" => Color::Yellow,
-            "feature" => Color::Green,
-            "performance" => Color::LightGreen,
-            "enhancement" => Color::Blue,
-            "User" => Color::LightBlue,
-            "Dev" => Color::Magenta,
-            "documentation" => Color::Cyan,
-            "design" => Color::LightCyan,
-            "refactor" => Color::LightYellow,
-            _ => Color::White,
+        let tag = normalize_tag(tag);
+        if let Ok(custom) = CUSTOM_TAG_COLORS.read() {
+            if let Some((_, color)) = custom.iter().find(|(name, _)| *name == tag) {
+                return *color;
+            }
         }
+
+        PREDEFINED_TAGS
+            .iter()
+            .find(|(name, _, _)| *name == tag)
+            .map_or(Color::White, |(_, color, _)| *color)
     }
 }
 
@@ -67,6 +410,14 @@ pub struct BoardColumn {
     pub id: String,
     pub name: String,
     pub tasks: Vec<Task>,
+    #[serde(default)]
+    pub collapsed: bool, // drawn as a narrow strip
+    #[serde(default)]
+    pub color: Option<String>, // accent for borders, named or hex
+    #[serde(default)]
+    pub width: Option<u16>, // share of the board width, DEFAULT_COLUMN_WEIGHT when unset
+    #[serde(default)]
+    pub backlog: bool, // overflow column the next card gets pulled from
 }
 
 impl BoardColumn {
@@ -75,8 +426,42 @@ impl BoardColumn {
             id,
             name,
             tasks: Vec::new(),
+            collapsed: false,
+            color: None,
+            width: None,
+            backlog: false,
         }
     }
+
+    // width weight of the column relative to the others
+    pub fn width_weight(&self) -> u16 {
+        self.width
+            .unwrap_or(DEFAULT_COLUMN_WEIGHT)
+            .clamp(MIN_COLUMN_WEIGHT, MAX_COLUMN_WEIGHT)
+    }
+
+    // make the column wider (or narrower), returns false at the limit
+    pub fn resize(&mut self, wider: bool) -> bool {
+        let current = self.width_weight();
+        let weight = if wider {
+            (current + 1).min(MAX_COLUMN_WEIGHT)
+        } else {
+            current.saturating_sub(1).max(MIN_COLUMN_WEIGHT)
+        };
+        // back to unset at the default so untouched boards stay equal
+        self.width = (weight != DEFAULT_COLUMN_WEIGHT).then_some(weight);
+        weight != current
+    }
+
+    // accent color for the column border and its cards, if set and valid
+    pub fn accent(&self) -> Option<Color> {
+        self.color.as_deref().and_then(parse_color)
+    }
+
+    // sum of the task estimates, 0 when none are set
+    pub fn total_estimate(&self) -> f32 {
+        self.tasks.iter().filter_map(|task| task.estimate).sum()
+    }
 }
 
 // Kanban board with dynamic columns
@@ -85,16 +470,72 @@ pub struct Board {
     pub columns: Vec<BoardColumn>,
 }
 
+impl Default for Board {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Board {
     // Create new board with default columns
     pub fn new() -> Self {
+        Self::from_columns(&template_columns(0))
+    }
+
+    // board with empty columns given as (id, name)
+    pub fn from_columns(columns: &[(String, String)]) -> Self {
         Self {
-            columns: vec![
-                BoardColumn::new("todo".to_string(), "To Do".to_string()),
-                BoardColumn::new("in_progress".to_string(), "In Progress".to_string()),
-                BoardColumn::new("testing".to_string(), "Testing".to_string()),
-                BoardColumn::new("done".to_string(), "Done".to_string()),
-            ],
+            columns: columns
+                .iter()
+                .map(|(id, name)| BoardColumn::new(id.clone(), name.clone()))
+                .collect(),
+        }
+    }
+
+    // put each column's tasks in their saved order (stable, so boards saved before
+    // the order existed keep their list order), then renumber
+    pub fn sort_by_order(&mut self) {
+        for column in &mut self.columns {
+            column.tasks.sort_by_key(|task| task.order);
+        }
+        self.renumber();
+    }
+
+    // store every task's current position as its order
+    pub fn renumber(&mut self) {
+        for column in &mut self.columns {
+            for (i, task) in column.tasks.iter_mut().enumerate() {
+                task.order = i as u32;
+            }
+        }
+    }
+
+    // id for a new column named name: its slug, with _2, _3, ... appended if taken
+    pub fn unique_column_id(&self, name: &str) -> String {
+        let slug = name.trim().to_lowercase().replace(' ', "_");
+        let taken = |id: &str| self.columns.iter().any(|col| col.id == id);
+        if !taken(&slug) {
+            return slug;
+        }
+        (2..)
+            .map(|n| format!("{}_{}", slug, n))
+            .find(|id| !taken(id))
+            .unwrap_or(slug)
+    }
+
+    // whether a column already has this name (ignoring case and surrounding spaces)
+    pub fn has_column_named(&self, name: &str) -> bool {
+        let name = name.trim().to_lowercase();
+        self.columns
+            .iter()
+            .any(|col| col.name.trim().to_lowercase() == name)
+    }
+
+    // a board always needs one column to put tasks in (malformed files may have none)
+    pub fn ensure_column(&mut self) {
+        if self.columns.is_empty() {
+            self.columns
+                .push(BoardColumn::new("todo".to_string(), "To Do".to_string()));
         }
     }
 
@@ -107,6 +548,113 @@ impl Board {
     pub fn get_column_mut(&mut self, index: usize) -> Option<&mut BoardColumn> {
         self.columns.get_mut(index)
     }
+
+    // find a task anywhere on the board by id, with its column index
+    pub fn find_task(&self, id: &str) -> Option<(usize, &Task)> {
+        self.columns.iter().enumerate().find_map(|(col_idx, col)| {
+            col.tasks
+                .iter()
+                .find(|task| task.id == id)
+                .map(|task| (col_idx, task))
+        })
+    }
+
+    // a task is blocked while any of its blockers on this board isn't done
+    pub fn is_blocked(&self, task: &Task) -> bool {
+        let done_idx = self.done_column_index();
+        task.blocked_by.iter().any(|id| {
+            self.find_task(id)
+                .is_some_and(|(col_idx, _)| Some(col_idx) != done_idx)
+        })
+    }
+
+    // the column marked as backlog, if any
+    pub fn backlog_index(&self) -> Option<usize> {
+        self.columns.iter().position(|col| col.backlog)
+    }
+
+    // move the top card of the backlog to the bottom of the first other column,
+    // returning the target column (None without a backlog, a card or another column)
+    pub fn pull_from_backlog(&mut self) -> Option<usize> {
+        let backlog = self.backlog_index()?;
+        let target = self.columns.iter().position(|col| !col.backlog)?;
+        if self.columns[backlog].tasks.is_empty() {
+            return None;
+        }
+        let task = self.columns[backlog].tasks.remove(0);
+        self.columns[target].tasks.push(task);
+        Some(target)
+    }
+
+    // index of the "done" column, falling back to the last column
+    pub fn done_column_index(&self) -> Option<usize> {
+        self.columns
+            .iter()
+            .position(|col| col.id == "done")
+            .or_else(|| self.columns.len().checked_sub(1))
+    }
+
+    // total number of tasks and how many sit in the last column
+    pub fn progress(&self) -> (usize, usize) {
+        let total = self.columns.iter().map(|col| col.tasks.len()).sum();
+        let done = self.columns.last().map_or(0, |col| col.tasks.len());
+        (total, done)
+    }
+
+    // each predefined tag with whether any card on the board carries it
+    pub fn predefined_tag_usage(&self) -> Vec<(&'static str, bool)> {
+        PREDEFINED_TAGS
+            .iter()
+            .map(|(tag, _, _)| {
+                let used = self
+                    .columns
+                    .iter()
+                    .flat_map(|col| &col.tasks)
+                    .any(|task| task.has_tag(tag));
+                (*tag, used)
+            })
+            .collect()
+    }
+
+    // append every column's tasks of another board to the same-named column here, adding
+    // columns that don't exist yet; returns where each of the other board's columns went
+    pub fn merge_from(&mut self, other: Board) -> Vec<usize> {
+        let mut targets = Vec::new();
+        for column in other.columns {
+            let target = match self.columns.iter().position(|col| col.name == column.name) {
+                Some(target) => {
+                    self.columns[target].tasks.extend(column.tasks);
+                    target
+                }
+                None => {
+                    let mut column = column;
+                    column.id = self.unique_column_id(&column.id);
+                    // keep a single backlog, the one already here wins
+                    column.backlog = column.backlog && self.backlog_index().is_none();
+                    self.columns.push(column);
+                    self.columns.len() - 1
+                }
+            };
+            targets.push(target);
+        }
+        targets
+    }
+
+    // every tag on the board with the number of cards carrying it, most used first
+    pub fn tag_counts(&self) -> Vec<(String, usize)> {
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for task in self.columns.iter().flat_map(|col| &col.tasks) {
+            for tag in &task.tags {
+                *counts.entry(tag.as_str()).or_default() += 1;
+            }
+        }
+        let mut counts: Vec<(String, usize)> = counts
+            .into_iter()
+            .map(|(tag, count)| (tag.to_string(), count))
+            .collect();
+        counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        counts
+    }
 }
 
 #[cfg(test)]
@@ -120,20 +668,135 @@ mod tests {
         assert_eq!(task.title, "Test Task");
         assert!(task.tags.is_empty());
         assert!(task.description.is_empty());
+        assert!(task.created_at.is_some());
+        assert_eq!(task.created_at, task.updated_at);
+    }
+
+    #[test]
+    fn test_pull_from_backlog() {
+        let mut board = Board::from_columns(&template_columns(2)); // starts with Backlog
+        assert_eq!(board.pull_from_backlog(), None); // nothing marked yet
+
+        board.columns[0].backlog = true;
+        assert_eq!(board.pull_from_backlog(), None); // empty backlog
+        for title in ["First", "Second"] {
+            board.columns[0].tasks.push(Task::new(title.to_string()));
+        }
+        board.columns[1].tasks.push(Task::new("Doing".to_string()));
+
+        assert_eq!(board.pull_from_backlog(), Some(1));
+        let titles: Vec<&str> = board.columns[1]
+            .tasks
+            .iter()
+            .map(|task| task.title.as_str())
+            .collect();
+        assert_eq!(titles, vec!["Doing", "First"]);
+        assert_eq!(board.columns[0].tasks[0].title, "Second");
+    }
+
+    #[test]
+    fn test_task_without_timestamps_loads() {
+        let json = r#"{"title":"Old","tags":[],"description":""}"#;
+        let task: Task = serde_json::from_str(json).unwrap();
+        assert_eq!(task.title, "Old");
+        assert!(task.created_at.is_none());
+        assert!(task.updated_at.is_none());
     }
 
     #[test]
     fn test_task_add_tag() {
         let mut task = Task::new("Task".to_string());
-        task.add_tag("bug".to_string());
-        task.add_tag("urgent".to_string());
-        task.add_tag("bug".to_string()); // Duplicate
+        assert!(task.add_tag("bug".to_string()));
+        assert!(task.add_tag("urgent".to_string()));
+        assert!(!task.add_tag("bug".to_string())); // Duplicate
 
         assert_eq!(task.tags.len(), 2);
         assert!(task.tags.contains(&"bug".to_string()));
         assert!(task.tags.contains(&"urgent".to_string()));
     }
 
+    #[test]
+    fn test_task_add_tag_normalizes() {
+        let mut task = Task::new("Task".to_string());
+        task.add_tag("  bug ".to_string());
+        task.add_tag("bug".to_string());
+        task.add_tag("#bug".to_string());
+        task.add_tag("Bug".to_string());
+        assert!(!task.add_tag(" # ".to_string())); // Empty after normalizing
+
+        assert_eq!(task.tags, vec!["bug"]);
+        assert_eq!(Task::get_tag_color("#Bug"), Color::Yellow);
+    }
+
+    #[test]
+    fn test_task_cycle_color() {
+        let mut task = Task::new("Task".to_string());
+        assert_eq!(task.accent(), None);
+
+        task.cycle_color();
+        assert_eq!(task.color.as_deref(), Some("red"));
+        assert_eq!(task.accent(), Some(Color::Red));
+
+        for _ in 1..TASK_COLOR_PALETTE.len() {
+            task.cycle_color();
+        }
+        assert_eq!(task.color.as_deref(), Some("magenta"));
+        task.cycle_color();
+        assert_eq!(task.color, None);
+    }
+
+    #[test]
+    fn test_task_rename_tag() {
+        let mut task = Task::new("Task".to_string());
+        task.add_tag("bgu".to_string());
+        task.add_tag("urgent".to_string());
+
+        assert!(task.rename_tag(0, "bug".to_string()));
+        assert_eq!(task.tags, vec!["bug", "urgent"]); // order kept
+        assert!(!task.rename_tag(0, "urgent".to_string())); // Duplicate
+        assert!(!task.rename_tag(5, "other".to_string()));
+        assert_eq!(task.tags, vec!["bug", "urgent"]);
+    }
+
+    #[test]
+    fn test_archive_done_before() {
+        let mut project = Project::new("Test".to_string());
+        for (title, updated_at) in [("Old", Some(100)), ("New", Some(500)), ("Legacy", None)] {
+            let mut task = Task::new(title.to_string());
+            task.updated_at = updated_at;
+            project.board.columns[3].tasks.push(task);
+        }
+        let mut open = Task::new("Open".to_string());
+        open.updated_at = Some(100);
+        project.board.columns[0].tasks.push(open);
+
+        assert_eq!(project.archive_done_before(200), 1);
+        assert_eq!(project.archived[0].title, "Old");
+        // unknown age and unfinished tasks stay
+        assert_eq!(project.board.columns[3].tasks.len(), 2);
+        assert_eq!(project.board.columns[0].tasks.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_estimate() {
+        assert_eq!(parse_estimate(" 3 "), Ok(Some(3.0)));
+        assert_eq!(parse_estimate("1.5h"), Ok(Some(1.5)));
+        assert_eq!(parse_estimate(""), Ok(None));
+        assert!(parse_estimate("soon").is_err());
+        assert!(parse_estimate("-2").is_err());
+        assert!(parse_estimate("NaN").is_err());
+        assert_eq!(format_estimate(1.5), "1.5h");
+        assert_eq!(format_estimate(12.0), "12h");
+
+        let mut column = BoardColumn::new("todo".to_string(), "To Do".to_string());
+        for estimate in [Some(2.0), None, Some(0.5)] {
+            let mut task = Task::new("Task".to_string());
+            task.estimate = estimate;
+            column.tasks.push(task);
+        }
+        assert_eq!(column.total_estimate(), 2.5);
+    }
+
     #[test]
     fn test_tag_colors() {
         assert_eq!(Task::get_tag_color("urgent"), Color::Red);
@@ -141,6 +804,156 @@ mod tests {
         assert_eq!(Task::get_tag_color("unknown_tag"), Color::White);
     }
 
+    #[test]
+    fn test_predefined_tag_colors_match_table() {
+        for (name, color, _) in PREDEFINED_TAGS {
+            assert_eq!(Task::get_tag_color(name), *color, "tag {}", name);
+        }
+    }
+
+    #[test]
+    fn test_custom_tag_colors() {
+        let mut colors = HashMap::new();
+        colors.insert("blocked".to_string(), "#ff8800".to_string());
+        colors.insert("waiting".to_string(), "magenta".to_string());
+        colors.insert("broken".to_string(), "not-a-color".to_string());
+        set_custom_tag_colors(&colors);
+
+        assert_eq!(Task::get_tag_color("blocked"), Color::Rgb(0xff, 0x88, 0x00));
+        assert_eq!(Task::get_tag_color("waiting"), Color::Magenta);
+        assert_eq!(Task::get_tag_color("broken"), Color::White);
+        assert_eq!(Task::get_tag_color("bug"), Color::Yellow); // Built-in still works
+    }
+
+    #[test]
+    fn test_task_ids_are_unique() {
+        let a = Task::new("A".to_string());
+        let b = Task::new("B".to_string());
+        assert_ne!(a.id, b.id);
+    }
+
+    #[test]
+    fn test_project_duplicate() {
+        let mut project = Project::new("Template".to_string());
+        let mut first = Task::new("First".to_string());
+        first.add_tag("bug".to_string());
+        let mut second = Task::new("Second".to_string());
+        second.blocked_by.push(first.id.clone());
+        project.board.columns[0].tasks.push(first);
+        project.board.columns[0].tasks.push(second);
+
+        let copy = project.duplicate();
+        assert_eq!(copy.name, "Template (copy)");
+        let tasks = &copy.board.columns[0].tasks;
+        assert_eq!(tasks[0].title, "First");
+        assert_eq!(tasks[0].tags, vec!["bug"]);
+        assert_ne!(tasks[0].id, project.board.columns[0].tasks[0].id);
+        assert_eq!(tasks[1].blocked_by, vec![tasks[0].id.clone()]);
+    }
+
+    #[test]
+    fn test_board_progress() {
+        let mut board = Board::new();
+        assert_eq!(board.progress(), (0, 0));
+
+        board.columns[0].tasks.push(Task::new("A".to_string()));
+        board.columns[1].tasks.push(Task::new("B".to_string()));
+        board.columns[3].tasks.push(Task::new("C".to_string()));
+        assert_eq!(board.progress(), (3, 1));
+    }
+
+    #[test]
+    fn test_predefined_tag_usage() {
+        let mut board = Board::new();
+        let mut task = Task::new("A".to_string());
+        task.add_tag("bug".to_string());
+        task.add_tag("custom".to_string());
+        board.columns[1].tasks.push(task);
+
+        let usage = board.predefined_tag_usage();
+        assert_eq!(usage.len(), PREDEFINED_TAGS.len());
+        assert!(usage.contains(&("bug", true)));
+        assert!(usage.contains(&("urgent", false)));
+        assert_eq!(usage.iter().filter(|(_, used)| *used).count(), 1);
+    }
+
+    #[test]
+    fn test_sort_by_order() {
+        let mut board = Board::new();
+        for (title, order) in [("C", 2), ("A", 0), ("B", 1)] {
+            let mut task = Task::new(title.to_string());
+            task.order = order;
+            board.columns[0].tasks.push(task);
+        }
+        // saved before orders existed: all 0, list order wins
+        for title in ["X", "Y"] {
+            board.columns[1].tasks.push(Task::new(title.to_string()));
+        }
+
+        board.sort_by_order();
+        let titles = |col: &BoardColumn| -> Vec<String> {
+            col.tasks.iter().map(|task| task.title.clone()).collect()
+        };
+        assert_eq!(titles(&board.columns[0]), ["A", "B", "C"]);
+        assert_eq!(titles(&board.columns[1]), ["X", "Y"]);
+        assert_eq!(board.columns[1].tasks[1].order, 1);
+    }
+
+    #[test]
+    fn test_column_resize() {
+        let mut column = BoardColumn::new("todo".to_string(), "To Do".to_string());
+        assert_eq!(column.width_weight(), DEFAULT_COLUMN_WEIGHT);
+
+        assert!(column.resize(true));
+        assert_eq!(column.width, Some(DEFAULT_COLUMN_WEIGHT + 1));
+        assert!(column.resize(false));
+        assert_eq!(column.width, None);
+
+        while column.resize(false) {}
+        assert_eq!(column.width_weight(), MIN_COLUMN_WEIGHT);
+        while column.resize(true) {}
+        assert_eq!(column.width_weight(), MAX_COLUMN_WEIGHT);
+    }
+
+    #[test]
+    fn test_unique_column_id() {
+        let mut board = Board::new();
+        assert_eq!(board.unique_column_id("In Review"), "in_review");
+        board.columns.push(BoardColumn::new(
+            board.unique_column_id("In Review"),
+            "In Review".to_string(),
+        ));
+        assert!(board.has_column_named(" in review "));
+        // a different name with the same slug still gets its own id
+        let id = board.unique_column_id("in review");
+        assert_eq!(id, "in_review_2");
+        board
+            .columns
+            .push(BoardColumn::new(id, "in review!".to_string()));
+        assert_eq!(board.unique_column_id("In Review"), "in_review_3");
+        assert_eq!(board.unique_column_id("Done"), "done_2");
+    }
+
+    #[test]
+    fn test_is_blocked() {
+        let mut board = Board::new();
+        let blocker = Task::new("Blocker".to_string());
+        let mut task = Task::new("Task".to_string());
+        task.blocked_by.push(blocker.id.clone());
+        board.columns[0].tasks.push(blocker);
+
+        assert!(board.is_blocked(&task));
+
+        // Once the blocker is done, the task is free
+        let blocker = board.columns[0].tasks.remove(0);
+        board.columns[3].tasks.push(blocker);
+        assert!(!board.is_blocked(&task));
+
+        // Unknown blockers (deleted/archived) don't block
+        task.blocked_by = vec!["gone".to_string()];
+        assert!(!board.is_blocked(&task));
+    }
+
     #[test]
     fn test_board_creation() {
         let board = Board::new();
@@ -156,4 +969,20 @@ mod tests {
         assert_eq!(col.name, "Column Name");
         assert!(col.tasks.is_empty());
     }
+
+    #[test]
+    fn test_activity_log_is_capped() {
+        let mut project = Project::new("Log".to_string());
+        for i in 0..MAX_LOG_ENTRIES + 3 {
+            project.record(format!("entry {}", i));
+        }
+        assert_eq!(project.log.len(), MAX_LOG_ENTRIES);
+        assert_eq!(project.log[0].message, "entry 3");
+        assert_eq!(
+            project.log.last().unwrap().message,
+            format!("entry {}", MAX_LOG_ENTRIES + 2)
+        );
+        // a copy starts with its own history
+        assert!(project.duplicate().log.is_empty());
+    }
 }
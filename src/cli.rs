@@ -0,0 +1,201 @@
+use crate::board::{self, Project, Task};
+use crate::storage;
+use std::io::{self, Write};
+use std::path::Path;
+
+// usage text for the command line interface
+const USAGE: &str = "Usage:
+  tui-kanban [--no-color]         Start the board (without colors, also when NO_COLOR is set)
+  tui-kanban add <title> [--project <name>] [--column <name>] [--tag <tag>]...
+  tui-kanban list [--json]        Print every task as project<TAB>column<TAB>title<TAB>tags
+  tui-kanban import --trello <file>
+                                  Add a project from a Trello board JSON export
+  tui-kanban import --github <owner/repo>
+                                  Add a project from the repo's open issues (uses GITHUB_TOKEN)";
+
+// run a subcommand if one was given, returning the exit code (None starts the TUI)
+pub fn run(args: &[String]) -> Option<i32> {
+    let (command, rest) = args.split_first()?;
+    // new projects (a fresh projects.json, imports) start with the configured columns
+    if matches!(command.as_str(), "add" | "import") {
+        let (settings, _) = storage::load_settings();
+        board::set_default_columns(&settings.default_columns);
+    }
+    let result = match command.as_str() {
+        "add" => add(rest),
+        "list" => list(rest),
+        "import" => import(rest),
+        "help" | "--help" | "-h" => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        other => Err(format!("unknown command '{}'\n{}", other, USAGE)),
+    };
+
+    Some(match result {
+        Ok(()) => 0,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            1
+        }
+    })
+}
+
+// get the value following a flag
+fn flag_value<'a>(
+    args: &mut impl Iterator<Item = &'a String>,
+    flag: &str,
+) -> Result<&'a String, String> {
+    args.next()
+        .ok_or_else(|| format!("missing value for {}", flag))
+}
+
+// print all projects and tasks to stdout without touching anything on disk
+fn list(args: &[String]) -> Result<(), String> {
+    let mut json = false;
+    for arg in args {
+        match arg.as_str() {
+            "--json" => json = true,
+            other => return Err(format!("unknown option '{}'", other)),
+        }
+    }
+
+    let projects = storage::load_projects_readonly();
+    let mut out = io::stdout().lock();
+    let result = if json {
+        let text = serde_json::to_string_pretty(&projects).map_err(|err| err.to_string())?;
+        writeln!(out, "{}", text)
+    } else {
+        write_task_lines(&mut out, &projects)
+    };
+
+    // a closed pipe (e.g. `| head`) just means the reader has seen enough
+    match result {
+        Err(err) if err.kind() != io::ErrorKind::BrokenPipe => Err(err.to_string()),
+        _ => Ok(()),
+    }
+}
+
+// one line per task so it stays easy to grep, cut and sort
+fn write_task_lines(out: &mut impl Write, projects: &[Project]) -> io::Result<()> {
+    for project in projects {
+        for column in &project.board.columns {
+            for task in &column.tasks {
+                writeln!(
+                    out,
+                    "{}\t{}\t{}\t{}",
+                    project.name,
+                    column.name,
+                    task.title,
+                    task.tags.join(",")
+                )?;
+            }
+        }
+    }
+    Ok(())
+}
+
+// add a task to a project/column without opening the TUI
+fn add(args: &[String]) -> Result<(), String> {
+    let mut title = None;
+    let mut project_name = None;
+    let mut column_name = None;
+    let mut tags = Vec::new();
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--project" | "-p" => project_name = Some(flag_value(&mut iter, arg)?),
+            "--column" | "-c" => column_name = Some(flag_value(&mut iter, arg)?),
+            "--tag" | "-t" => tags.push(flag_value(&mut iter, arg)?.clone()),
+            flag if flag.starts_with("--") => return Err(format!("unknown option '{}'", flag)),
+            _ if title.is_none() => title = Some(arg.clone()),
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+    let title = title
+        .filter(|t| !t.trim().is_empty())
+        .ok_or_else(|| format!("missing task title\n{}", USAGE))?;
+
+    let mut projects = storage::load_projects();
+
+    // default to the first project / first column
+    let project = match project_name {
+        Some(name) => projects
+            .iter_mut()
+            .find(|p| p.name.eq_ignore_ascii_case(name))
+            .ok_or_else(|| format!("project '{}' not found", name))?,
+        None => projects.first_mut().ok_or("no projects found")?,
+    };
+    let column = match column_name {
+        Some(name) => project
+            .board
+            .columns
+            .iter_mut()
+            .find(|c| c.name.eq_ignore_ascii_case(name) || c.id.eq_ignore_ascii_case(name))
+            .ok_or_else(|| format!("column '{}' not found in '{}'", name, project.name))?,
+        None => project
+            .board
+            .columns
+            .first_mut()
+            .ok_or_else(|| format!("project '{}' has no columns", project.name))?,
+    };
+
+    let mut task = Task::new(title);
+    for tag in tags {
+        task.add_tag(tag);
+    }
+    let message = format!(
+        "Added \"{}\" to {} / {}",
+        task.title, project.name, column.name
+    );
+    column.tasks.push(task);
+
+    // same backup as every other save, and only report success once it's written
+    let _ = storage::backup_projects();
+    storage::save_projects(&mut projects).map_err(|err| format!("failed to save: {}", err))?;
+    println!("{}", message);
+    Ok(())
+}
+
+// import a board exported from another tool as a new project
+fn import(args: &[String]) -> Result<(), String> {
+    let mut trello_path = None;
+    let mut github_repo = None;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--trello" => trello_path = Some(flag_value(&mut iter, arg)?),
+            "--github" => github_repo = Some(flag_value(&mut iter, arg)?),
+            other => return Err(format!("unknown option '{}'", other)),
+        }
+    }
+
+    let project = match (trello_path, github_repo) {
+        (Some(path), None) => storage::import_trello(Path::new(path))
+            .map_err(|err| format!("failed to import '{}': {}", path, err))?,
+        (None, Some(repo)) => storage::import_github(repo)
+            .map_err(|err| format!("failed to import '{}': {}", repo, err))?,
+        _ => {
+            return Err(format!(
+                "expected one of --trello <file> or --github <owner/repo>\n{}",
+                USAGE
+            ))
+        }
+    };
+    let task_count: usize = project.board.columns.iter().map(|c| c.tasks.len()).sum();
+    let message = format!(
+        "Imported \"{}\" ({} columns, {} tasks)",
+        project.name,
+        project.board.columns.len(),
+        task_count
+    );
+
+    let mut projects = storage::load_projects();
+    projects.push(project);
+    // like add, only report success once it's written
+    let _ = storage::backup_projects();
+    storage::save_projects(&mut projects).map_err(|err| format!("failed to save: {}", err))?;
+    println!("{}", message);
+    Ok(())
+}
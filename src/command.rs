@@ -0,0 +1,96 @@
+// commands typed after ':' in normal mode
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    Add(String),            // :add <title>
+    Tag(String),            // :tag <name>
+    ColumnAdd(String),      // :column add <name>
+    ColumnRename(String),   // :column rename <name>
+    ColumnDelete,           // :column delete
+    Project(String),        // :project <name>
+    Filter(Option<String>), // :filter [tag], without a tag clears the filter
+    ExportMarkdown,         // :export md
+    Status,                 // :status
+    Help,                   // :help
+    Quit,                   // :quit or :q
+}
+
+// names listed when a command isn't recognized
+const COMMANDS: &str =
+    "add, tag, column add/rename/delete, project, filter, export md, status, help, quit";
+
+// parse a command line (without the ':'), e.g. "column add Review"
+pub fn parse(input: &str) -> Result<Command, String> {
+    let input = input.trim();
+    let (name, rest) = split_word(input);
+    let command = match name {
+        "add" => Command::Add(required(rest, "add <title>")?),
+        "tag" => Command::Tag(required(rest, "tag <name>")?),
+        "column" | "col" => {
+            let (sub, name) = split_word(rest);
+            match sub {
+                "add" => Command::ColumnAdd(required(name, "column add <name>")?),
+                "rename" => Command::ColumnRename(required(name, "column rename <name>")?),
+                "delete" => Command::ColumnDelete,
+                _ => return Err("usage: column add|rename <name>, column delete".to_string()),
+            }
+        }
+        "project" => Command::Project(required(rest, "project <name>")?),
+        "filter" => Command::Filter((!rest.is_empty()).then(|| rest.to_string())),
+        "export" => match rest {
+            "md" | "markdown" => Command::ExportMarkdown,
+            _ => return Err("usage: export md".to_string()),
+        },
+        "status" => Command::Status,
+        "help" => Command::Help,
+        "quit" | "q" => Command::Quit,
+        "" => return Err("empty command".to_string()),
+        other => return Err(format!("unknown command '{}' (try {})", other, COMMANDS)),
+    };
+    Ok(command)
+}
+
+// first word and the trimmed rest
+fn split_word(input: &str) -> (&str, &str) {
+    match input.split_once(char::is_whitespace) {
+        Some((word, rest)) => (word, rest.trim()),
+        None => (input, ""),
+    }
+}
+
+// argument that must not be empty, with the usage as the error
+fn required(arg: &str, usage: &str) -> Result<String, String> {
+    if arg.is_empty() {
+        Err(format!("usage: {}", usage))
+    } else {
+        Ok(arg.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_commands() {
+        assert_eq!(
+            parse("add Write the docs"),
+            Ok(Command::Add("Write the docs".to_string()))
+        );
+        assert_eq!(
+            parse("  column   add  Code Review "),
+            Ok(Command::ColumnAdd("Code Review".to_string()))
+        );
+        assert_eq!(parse("column delete"), Ok(Command::ColumnDelete));
+        assert_eq!(parse("filter"), Ok(Command::Filter(None)));
+        assert_eq!(
+            parse("filter bug"),
+            Ok(Command::Filter(Some("bug".to_string())))
+        );
+        assert_eq!(parse("export md"), Ok(Command::ExportMarkdown));
+        assert_eq!(parse("q"), Ok(Command::Quit));
+
+        assert_eq!(parse("add"), Err("usage: add <title>".to_string()));
+        assert!(parse("export pdf").is_err());
+        assert!(parse("fly").unwrap_err().contains("unknown command 'fly'"));
+    }
+}
@@ -0,0 +1,279 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// seconds since the unix epoch (UTC)
+pub type Timestamp = i64;
+
+// current time as a unix timestamp
+pub fn now() -> Timestamp {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() as Timestamp)
+}
+
+// offset of local time from UTC in seconds at the given instant
+#[cfg(unix)]
+fn local_offset(ts: Timestamp) -> i64 {
+    let time = ts as libc::time_t;
+    // SAFETY: localtime_r only writes into the tm we hand it
+    unsafe {
+        let mut tm: libc::tm = std::mem::zeroed();
+        if libc::localtime_r(&time, &mut tm).is_null() {
+            0
+        } else {
+            tm.tm_gmtoff
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn local_offset(_ts: Timestamp) -> i64 {
+    0
+}
+
+// broken down calendar date and time
+struct DateTime {
+    year: i64,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+}
+
+// convert days since epoch to (year, month, day), see Howard Hinnant's civil_from_days
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+// split a timestamp (already shifted to the wanted zone) into calendar fields
+fn to_datetime(ts: Timestamp) -> DateTime {
+    let days = ts.div_euclid(86_400);
+    let secs = ts.rem_euclid(86_400);
+    let (year, month, day) = civil_from_days(days);
+    DateTime {
+        year,
+        month,
+        day,
+        hour: (secs / 3600) as u32,
+        minute: (secs % 3600 / 60) as u32,
+    }
+}
+
+// date format used when settings.json doesn't set a valid one
+pub const DEFAULT_DATE_FORMAT: &str = "%Y-%m-%d";
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+// the strftime subset we understand: %Y %y %m %d %e %b %B %H %M and %%
+pub fn is_valid_format(format: &str) -> bool {
+    let mut chars = format.chars();
+    while let Some(c) = chars.next() {
+        if c == '%'
+            && !matches!(
+                chars.next(),
+                Some('Y' | 'y' | 'm' | 'd' | 'e' | 'b' | 'B' | 'H' | 'M' | '%')
+            )
+        {
+            return false;
+        }
+    }
+    !format.trim().is_empty()
+}
+
+// expand a (valid) strftime-style format for the given date and time
+fn format_fields(dt: &DateTime, format: &str) -> String {
+    let mut out = String::new();
+    let mut chars = format.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            out.push(c);
+            continue;
+        }
+        let month = MONTHS[(dt.month as usize).clamp(1, 12) - 1];
+        match chars.next() {
+            Some('Y') => out.push_str(&format!("{:04}", dt.year)),
+            Some('y') => out.push_str(&format!("{:02}", dt.year.rem_euclid(100))),
+            Some('m') => out.push_str(&format!("{:02}", dt.month)),
+            Some('d') => out.push_str(&format!("{:02}", dt.day)),
+            Some('e') => out.push_str(&dt.day.to_string()),
+            Some('b') => out.push_str(&month[..3]),
+            Some('B') => out.push_str(month),
+            Some('H') => out.push_str(&format!("{:02}", dt.hour)),
+            Some('M') => out.push_str(&format!("{:02}", dt.minute)),
+            Some(other) => out.push(other),
+            None => {}
+        }
+    }
+    out
+}
+
+// the format without its year, for places that are short on space ("%d/%m/%Y" -> "%d/%m")
+pub fn short_format(format: &str) -> String {
+    let short = format.replace("%Y", "").replace("%y", "");
+    let short = short.trim_matches(|c: char| " -/.,".contains(c));
+    if short.is_empty() {
+        format.to_string()
+    } else {
+        short.to_string()
+    }
+}
+
+// format a timestamp in local time with the date format plus "HH:MM"
+pub fn format_local(ts: Timestamp, date_format: &str) -> String {
+    let dt = to_datetime(ts + local_offset(ts));
+    format!(
+        "{} {:02}:{:02}",
+        format_fields(&dt, date_format),
+        dt.hour,
+        dt.minute
+    )
+}
+
+// reformat a stored "YYYY-MM-DD" date, left as it is if it doesn't parse
+pub fn format_date(date: &str, date_format: &str) -> String {
+    let Some(date) = parse_date(date) else {
+        return date.to_string();
+    };
+    let field = |range: std::ops::Range<usize>| date[range].parse().unwrap_or(1);
+    let dt = DateTime {
+        year: field(0..4) as i64,
+        month: field(5..7),
+        day: field(8..10),
+        hour: 0,
+        minute: 0,
+    };
+    format_fields(&dt, date_format)
+}
+
+// format a timestamp in local time as "HH:MM"
+pub fn format_local_time(ts: Timestamp) -> String {
+    let dt = to_datetime(ts + local_offset(ts));
+    format!("{:02}:{:02}", dt.hour, dt.minute)
+}
+
+// today's local date as "YYYY-MM-DD"
+pub fn today_local() -> String {
+    let ts = now();
+    let dt = to_datetime(ts + local_offset(ts));
+    format!("{:04}-{:02}-{:02}", dt.year, dt.month, dt.day)
+}
+
+// how long ago something happened, in its largest unit ("just now", "5m ago", "2d ago")
+pub fn humanize_duration(secs: i64) -> String {
+    const MINUTE: i64 = 60;
+    const HOUR: i64 = 60 * MINUTE;
+    const DAY: i64 = 24 * HOUR;
+    // a clock that went backwards counts as fresh
+    match secs {
+        s if s < MINUTE => "just now".to_string(),
+        s if s < HOUR => format!("{}m ago", s / MINUTE),
+        s if s < DAY => format!("{}h ago", s / HOUR),
+        s if s < 30 * DAY => format!("{}d ago", s / DAY),
+        s if s < 365 * DAY => format!("{}mo ago", s / (30 * DAY)),
+        s => format!("{}y ago", s / (365 * DAY)),
+    }
+}
+
+fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+// check a "YYYY-MM-DD" date (single digit month/day allowed) and return it zero padded
+pub fn parse_date(input: &str) -> Option<String> {
+    let mut parts = input.trim().split('-');
+    let year: i64 = parts.next()?.parse().ok()?;
+    let month: u32 = parts.next()?.parse().ok()?;
+    let day: u32 = parts.next()?.parse().ok()?;
+    if parts.next().is_some() || !(1..=9999).contains(&year) {
+        return None;
+    }
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => return None,
+    };
+    if !(1..=days_in_month).contains(&day) {
+        return None;
+    }
+    Some(format!("{:04}-{:02}-{:02}", year, month, day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_datetime() {
+        let dt = to_datetime(0);
+        assert_eq!((dt.year, dt.month, dt.day), (1970, 1, 1));
+
+        // 2024-02-29 13:45:30 UTC (leap day)
+        let dt = to_datetime(1_709_214_330);
+        assert_eq!((dt.year, dt.month, dt.day), (2024, 2, 29));
+        assert_eq!((dt.hour, dt.minute), (13, 45));
+    }
+
+    #[test]
+    fn test_humanize_duration() {
+        assert_eq!(humanize_duration(-30), "just now");
+        assert_eq!(humanize_duration(59), "just now");
+        assert_eq!(humanize_duration(5 * 60), "5m ago");
+        assert_eq!(humanize_duration(3 * 3600 + 59 * 60), "3h ago");
+        assert_eq!(humanize_duration(2 * 86_400), "2d ago");
+        assert_eq!(humanize_duration(95 * 86_400), "3mo ago");
+        assert_eq!(humanize_duration(800 * 86_400), "2y ago");
+    }
+
+    #[test]
+    fn test_date_formats() {
+        assert!(is_valid_format("%d/%m/%Y"));
+        assert!(is_valid_format("%e %B %Y, 100%%"));
+        assert!(!is_valid_format("%Q"));
+        assert!(!is_valid_format("%Y-%"));
+        assert!(!is_valid_format(""));
+
+        assert_eq!(format_date("2026-03-07", "%d/%m/%Y"), "07/03/2026");
+        assert_eq!(format_date("2026-03-07", "%b %e, %y"), "Mar 7, 26");
+        assert_eq!(format_date("2026-03-07", "%B"), "March");
+        assert_eq!(format_date("soon", "%d/%m/%Y"), "soon");
+
+        assert_eq!(short_format("%Y-%m-%d"), "%m-%d");
+        assert_eq!(short_format("%d.%m.%Y"), "%d.%m");
+        assert_eq!(short_format("%b %e, %Y"), "%b %e");
+        assert_eq!(short_format("%Y"), "%Y");
+    }
+
+    #[test]
+    fn test_parse_date() {
+        assert_eq!(parse_date("2026-3-7"), Some("2026-03-07".to_string()));
+        assert_eq!(parse_date(" 2024-02-29 "), Some("2024-02-29".to_string()));
+        assert_eq!(parse_date("2023-02-29"), None);
+        assert_eq!(parse_date("2026-13-01"), None);
+        assert_eq!(parse_date("2026-04-31"), None);
+        assert_eq!(parse_date("tomorrow"), None);
+        assert_eq!(parse_date("2026-01-01-01"), None);
+    }
+}
@@ -0,0 +1,573 @@
+use crate::app::InputMode;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+// actions that can be bound to keys in normal mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Action {
+    Quit,
+    MoveLeft,
+    MoveDown,
+    MoveUp,
+    MoveRight,
+    MoveToTop,
+    MoveToBottom,
+    MoveColumnLeft,
+    MoveColumnRight,
+    AddColumn,
+    RenameColumn,
+    DeleteColumn,
+    OpenTask,
+    AddTask,
+    AddTasks,
+    AddTag,
+    RenameTagEverywhere,
+    MoveTaskForward,
+    MoveTaskBackward,
+    MoveTaskToColumn,
+    DeleteTask,
+    CutTask,
+    PasteTask,
+    ExportMarkdown,
+    ShowHelp,
+    OpenProjectList,
+    ToggleConfirmDelete,
+    ToggleTagFilter,
+    ToggleTheme,
+    ArchiveTask,
+    OpenArchive,
+    ToggleCollapseColumn,
+    SetColumnColor,
+    SortColumn,
+    ToggleExpandCard,
+    OpenTagLegend,
+    WriteStatus,
+    ToggleMark,
+    NextProject,
+    PreviousProject,
+    OpenUpcoming,
+    RenameTask,
+    WidenColumn,
+    NarrowColumn,
+    OpenCommandLine,
+    OpenMinimap,
+    TagColumn,
+    UntagColumn,
+    ClearColumn,
+    ToggleBacklogColumn,
+    PullFromBacklog,
+    OpenActivityLog,
+    ToggleDescriptions,
+}
+
+// default bindings, identical to the original hardcoded keys
+const DEFAULT_BINDINGS: &[(Action, &[&str])] = &[
+    (Action::Quit, &["q"]),
+    (Action::MoveLeft, &["h", "Left"]),
+    (Action::MoveDown, &["j", "Down"]),
+    (Action::MoveUp, &["k", "Up"]),
+    (Action::MoveRight, &["l", "Right"]),
+    (Action::MoveToTop, &["g", "Home"]),
+    (Action::MoveToBottom, &["G", "End"]),
+    (Action::MoveColumnLeft, &["H", "<"]),
+    (Action::MoveColumnRight, &["L", ">"]),
+    (Action::AddColumn, &["C"]),
+    (Action::RenameColumn, &["R"]),
+    (Action::DeleteColumn, &["D"]),
+    (Action::OpenTask, &["Enter"]),
+    (Action::AddTask, &["a"]),
+    (Action::AddTasks, &["A"]),
+    (Action::AddTag, &["t"]),
+    (Action::RenameTagEverywhere, &["%"]),
+    (Action::MoveTaskForward, &["m"]),
+    (Action::MoveTaskBackward, &["n"]),
+    (Action::MoveTaskToColumn, &["M"]),
+    (Action::DeleteTask, &["d"]),
+    (Action::CutTask, &["x"]),
+    (Action::PasteTask, &["p"]),
+    (Action::ExportMarkdown, &["E"]),
+    (Action::ShowHelp, &["?"]),
+    (Action::OpenProjectList, &["Ctrl+p"]),
+    (Action::ToggleConfirmDelete, &["Ctrl+d"]),
+    (Action::ToggleTagFilter, &["f"]),
+    (Action::ToggleTheme, &["Ctrl+t"]),
+    (Action::ArchiveTask, &["X"]),
+    (Action::OpenArchive, &["v"]),
+    (Action::ToggleCollapseColumn, &["z"]),
+    (Action::SetColumnColor, &["c"]),
+    (Action::SortColumn, &["s"]),
+    (Action::ToggleExpandCard, &["o"]),
+    (Action::OpenTagLegend, &["T"]),
+    (Action::WriteStatus, &["S"]),
+    (Action::ToggleMark, &["Space"]),
+    (Action::NextProject, &["Ctrl+n"]),
+    (Action::PreviousProject, &["Ctrl+b"]),
+    (Action::OpenUpcoming, &["U"]),
+    (Action::RenameTask, &["r"]),
+    (Action::WidenColumn, &["+"]),
+    (Action::NarrowColumn, &["-"]),
+    (Action::OpenCommandLine, &[":"]),
+    (Action::OpenMinimap, &["O"]),
+    (Action::TagColumn, &["*"]),
+    (Action::UntagColumn, &["#"]),
+    (Action::ClearColumn, &["Ctrl+k"]),
+    (Action::ToggleBacklogColumn, &["B"]),
+    (Action::PullFromBacklog, &["P"]),
+    (Action::OpenActivityLog, &["J"]),
+    (Action::ToggleDescriptions, &["i"]),
+];
+
+// keys of a footer hint: whatever is bound to the actions (normal mode, so it follows
+// keybindings.json) or the fixed keys main.rs handles in every other mode
+pub enum HintKeys {
+    Bound(&'static [Action]),
+    Fixed(&'static str),
+}
+
+// one "keys: what they do" entry of the footer legend
+pub struct Hint {
+    pub keys: HintKeys,
+    pub text: &'static str,
+}
+
+const fn bound(actions: &'static [Action], text: &'static str) -> Hint {
+    Hint {
+        keys: HintKeys::Bound(actions),
+        text,
+    }
+}
+
+const fn fixed(keys: &'static str, text: &'static str) -> Hint {
+    Hint {
+        keys: HintKeys::Fixed(keys),
+        text,
+    }
+}
+
+const NORMAL_HINTS: &[Hint] = &[
+    bound(
+        &[
+            Action::MoveLeft,
+            Action::MoveDown,
+            Action::MoveUp,
+            Action::MoveRight,
+        ],
+        "navigate",
+    ),
+    bound(&[Action::OpenTask], "open task"),
+    bound(&[Action::AddTask], "add task"),
+    bound(&[Action::AddTag], "add tag"),
+    bound(
+        &[Action::MoveTaskForward, Action::MoveTaskBackward],
+        "move task",
+    ),
+    bound(
+        &[Action::MoveColumnLeft, Action::MoveColumnRight],
+        "move column",
+    ),
+    bound(&[Action::DeleteTask], "delete task"),
+    bound(&[Action::OpenCommandLine], "command"),
+    bound(&[Action::ShowHelp], "help"),
+    bound(&[Action::Quit], "quit"),
+];
+
+const PROMPT_HINTS: &[Hint] = &[
+    fixed("Enter", "submit"),
+    fixed("Esc", "cancel"),
+    fixed("Ctrl+W/U", "delete word/line"),
+    fixed("Ctrl+V", "paste"),
+];
+
+const ADDING_TAG_HINTS: &[Hint] = &[
+    fixed("↑/↓", "pick a suggestion"),
+    fixed("Enter", "add"),
+    fixed("Esc", "cancel"),
+    fixed("Ctrl+W/U", "delete word/line"),
+];
+
+const EDITING_FIELD_HINTS: &[Hint] = &[
+    fixed("Enter", "save"),
+    fixed("Esc", "cancel"),
+    fixed("Ctrl+W/U", "delete word/line"),
+    fixed("Ctrl+V", "paste"),
+];
+
+const EDITING_DESCRIPTION_HINTS: &[Hint] = &[
+    fixed("Enter", "new line"),
+    fixed("arrows/Home/End", "move"),
+    fixed("Esc", "save"),
+    fixed("Ctrl+W/U", "delete word/line"),
+    fixed("Ctrl+V", "paste"),
+];
+
+const VIEWING_TASK_HINTS: &[Hint] = &[
+    fixed("Tab", "switch field"),
+    fixed("Enter", "edit"),
+    fixed("j/k", "select tag / scroll"),
+    fixed("e/d/1-9", "edit/remove tag"),
+    fixed("/ n/N", "search"),
+    fixed("E/D", "estimate/due date"),
+    fixed("c", "color"),
+    fixed("b", "blockers"),
+    fixed("p", "move to project"),
+    fixed("Esc", "close"),
+];
+
+const SEARCHING_HINTS: &[Hint] = &[fixed("Enter", "search"), fixed("Esc", "cancel")];
+
+const PROJECT_LIST_HINTS: &[Hint] = &[
+    fixed("j/k", "navigate"),
+    fixed("Enter", "open"),
+    fixed("a", "add"),
+    fixed("c", "color"),
+    fixed("y", "duplicate"),
+    fixed("M", "merge"),
+    fixed("d", "delete"),
+    fixed("Esc", "close"),
+];
+
+const PICK_PROJECT_HINTS: &[Hint] = &[
+    fixed("j/k", "navigate"),
+    fixed("Enter", "pick project"),
+    fixed("Esc", "cancel"),
+];
+
+const PICK_TEMPLATE_HINTS: &[Hint] = &[
+    fixed("j/k", "navigate"),
+    fixed("Enter", "create"),
+    fixed("Esc", "cancel"),
+];
+
+const CONFIRM_HINTS: &[Hint] = &[fixed("y", "delete"), fixed("n/Esc", "cancel")];
+
+const HELP_HINTS: &[Hint] = &[
+    fixed("j/k", "scroll"),
+    fixed("PgUp/PgDn", "page"),
+    fixed("g/G", "top/bottom"),
+    fixed("Esc/?", "close"),
+];
+
+const ACTIVITY_LOG_HINTS: &[Hint] = &[
+    fixed("j/k", "scroll"),
+    fixed("PgUp/PgDn", "page"),
+    fixed("g/G", "newest/oldest"),
+    fixed("Esc/J", "close"),
+];
+
+const ARCHIVE_HINTS: &[Hint] = &[
+    fixed("j/k", "navigate"),
+    fixed("Enter/r", "restore"),
+    fixed("Esc", "close"),
+];
+
+const TAG_LEGEND_HINTS: &[Hint] = &[
+    fixed("j/k", "navigate"),
+    fixed("Enter", "filter by tag"),
+    fixed("Esc/T", "close"),
+];
+
+const UPCOMING_HINTS: &[Hint] = &[
+    fixed("j/k", "navigate"),
+    fixed("Enter", "jump to card"),
+    fixed("Esc/U", "close"),
+];
+
+const MINIMAP_HINTS: &[Hint] = &[
+    fixed("hjkl", "move selection"),
+    fixed("Enter/Esc/O", "back to board"),
+];
+
+const BLOCKER_HINTS: &[Hint] = &[
+    fixed("j/k", "navigate"),
+    fixed("Enter/Space", "toggle"),
+    fixed("Esc", "back"),
+];
+
+// the footer legend for a mode, matching the key handling in main.rs
+pub fn hints_for(mode: &InputMode) -> &'static [Hint] {
+    match mode {
+        InputMode::Normal => NORMAL_HINTS,
+        InputMode::AddingTag => ADDING_TAG_HINTS,
+        InputMode::AddingTask
+        | InputMode::AddingColumn
+        | InputMode::RenamingColumn
+        | InputMode::ColoringColumn
+        | InputMode::RenamingTagEverywhere
+        | InputMode::FilteringTag
+        | InputMode::TaggingColumn
+        | InputMode::UntaggingColumn
+        | InputMode::AddingProject
+        | InputMode::ColoringProject
+        | InputMode::Command => PROMPT_HINTS,
+        InputMode::EditingTitle
+        | InputMode::EditingTag
+        | InputMode::EditingEstimate
+        | InputMode::EditingDueDate => EDITING_FIELD_HINTS,
+        InputMode::EditingDescription => EDITING_DESCRIPTION_HINTS,
+        InputMode::ViewingTask => VIEWING_TASK_HINTS,
+        InputMode::SearchingDescription => SEARCHING_HINTS,
+        InputMode::ProjectList => PROJECT_LIST_HINTS,
+        InputMode::MovingTaskToProject | InputMode::MergingProject => PICK_PROJECT_HINTS,
+        InputMode::PickingTemplate => PICK_TEMPLATE_HINTS,
+        InputMode::ConfirmDelete => CONFIRM_HINTS,
+        InputMode::ViewingHelp => HELP_HINTS,
+        InputMode::ViewingArchive => ARCHIVE_HINTS,
+        InputMode::ActivityLog => ACTIVITY_LOG_HINTS,
+        InputMode::TagLegend => TAG_LEGEND_HINTS,
+        InputMode::Upcoming => UPCOMING_HINTS,
+        InputMode::Minimap => MINIMAP_HINTS,
+        InputMode::PickingBlocker => BLOCKER_HINTS,
+    }
+}
+
+// one key or a list of keys for an action in keybindings.json
+#[derive(Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum KeyList {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl KeyList {
+    fn keys(&self) -> Vec<String> {
+        match self {
+            KeyList::One(key) => vec![key.clone()],
+            KeyList::Many(keys) => keys.clone(),
+        }
+    }
+}
+
+// a key press as stored in the keymap (shift is part of the char itself)
+type Binding = (KeyCode, KeyModifiers);
+
+// maps key presses to actions
+pub struct KeyMap {
+    bindings: HashMap<Binding, Action>,
+    labels: HashMap<Action, Vec<String>>, // keys as written, for the footer legend
+}
+
+impl Default for KeyMap {
+    fn default() -> Self {
+        Self::from_config(&HashMap::new()).0
+    }
+}
+
+impl KeyMap {
+    // build keymap from user overrides (action name -> keys), returning any problems found
+    pub fn from_config(overrides: &HashMap<String, KeyList>) -> (Self, Vec<String>) {
+        let mut errors = Vec::new();
+
+        // resolve user overrides first so they win over defaults
+        let mut user: Vec<(Action, Vec<String>)> = Vec::new();
+        let mut names: Vec<&String> = overrides.keys().collect();
+        names.sort();
+        for name in names {
+            let value = serde_json::Value::String(name.clone());
+            match serde_json::from_value::<Action>(value) {
+                Ok(action) => user.push((action, overrides[name].keys())),
+                Err(_) => errors.push(format!("unknown action '{}'", name)),
+            }
+        }
+
+        let defaults = DEFAULT_BINDINGS
+            .iter()
+            .filter(|(action, _)| !user.iter().any(|(a, _)| a == action))
+            .map(|(action, keys)| (*action, keys.iter().map(|k| k.to_string()).collect()));
+
+        let mut bindings: HashMap<Binding, Action> = HashMap::new();
+        let mut labels: HashMap<Action, Vec<String>> = HashMap::new();
+        for (action, keys) in user.clone().into_iter().chain(defaults) {
+            for key in keys {
+                let Some(binding) = parse_key(&key) else {
+                    errors.push(format!("invalid key '{}' for {:?}", key, action));
+                    continue;
+                };
+                match bindings.get(&binding) {
+                    Some(existing) if *existing != action => errors.push(format!(
+                        "key '{}' bound to both {:?} and {:?}",
+                        key, existing, action
+                    )),
+                    _ => {
+                        bindings.insert(binding, action);
+                        labels.entry(action).or_default().push(key);
+                    }
+                }
+            }
+        }
+
+        (Self { bindings, labels }, errors)
+    }
+
+    // keys shown for a hint: the first key of each bound action, None if none is bound
+    pub fn hint_keys(&self, hint: &Hint) -> Option<String> {
+        match hint.keys {
+            HintKeys::Fixed(keys) => Some(keys.to_string()),
+            HintKeys::Bound(actions) => {
+                let keys: Vec<&str> = actions
+                    .iter()
+                    .filter_map(|action| self.labels.get(action)?.first())
+                    .map(String::as_str)
+                    .collect();
+                (!keys.is_empty()).then(|| keys.join("/"))
+            }
+        }
+    }
+
+    // look up the action for a key press
+    pub fn action_for(&self, key: KeyEvent) -> Option<Action> {
+        self.bindings
+            .get(&normalize(key.code, key.modifiers))
+            .copied()
+    }
+}
+
+// drop modifiers that don't matter for matching (shift is already in the char)
+fn normalize(code: KeyCode, modifiers: KeyModifiers) -> Binding {
+    let modifiers = modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT);
+    let code = match code {
+        // crossterm reports Ctrl+p as Char('p') with CONTROL, keep letters lowercase then
+        KeyCode::Char(c) if modifiers.contains(KeyModifiers::CONTROL) => {
+            KeyCode::Char(c.to_ascii_lowercase())
+        }
+        code => code,
+    };
+    (code, modifiers)
+}
+
+// parse a key like "h", "H", "Enter", "PageDown", "Ctrl+p" or "Alt+x"
+pub fn parse_key(s: &str) -> Option<Binding> {
+    let mut modifiers = KeyModifiers::NONE;
+    let mut rest = s;
+    loop {
+        let lower = rest.to_ascii_lowercase();
+        if rest.chars().count() > 1 && lower.starts_with("ctrl+") {
+            modifiers |= KeyModifiers::CONTROL;
+            rest = &rest[5..];
+        } else if rest.chars().count() > 1 && lower.starts_with("alt+") {
+            modifiers |= KeyModifiers::ALT;
+            rest = &rest[4..];
+        } else {
+            break;
+        }
+    }
+
+    let mut chars = rest.chars();
+    let code = match (chars.next(), chars.next()) {
+        (Some(c), None) => KeyCode::Char(c),
+        _ => match rest.to_ascii_lowercase().as_str() {
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "enter" => KeyCode::Enter,
+            "esc" => KeyCode::Esc,
+            "tab" => KeyCode::Tab,
+            "backtab" => KeyCode::BackTab,
+            "backspace" => KeyCode::Backspace,
+            "delete" => KeyCode::Delete,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            "space" => KeyCode::Char(' '),
+            other => {
+                let n = other.strip_prefix('f')?.parse::<u8>().ok()?;
+                if !(1..=12).contains(&n) {
+                    return None;
+                }
+                KeyCode::F(n)
+            }
+        },
+    };
+
+    Some(normalize(code, modifiers))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_default_bindings() {
+        let keymap = KeyMap::default();
+        assert_eq!(
+            keymap.action_for(press(KeyCode::Char('h'))),
+            Some(Action::MoveLeft)
+        );
+        assert_eq!(
+            keymap.action_for(press(KeyCode::Left)),
+            Some(Action::MoveLeft)
+        );
+        assert_eq!(
+            keymap.action_for(KeyEvent::new(KeyCode::Char('H'), KeyModifiers::SHIFT)),
+            Some(Action::MoveColumnLeft)
+        );
+        assert_eq!(
+            keymap.action_for(KeyEvent::new(KeyCode::Char('>'), KeyModifiers::SHIFT)),
+            Some(Action::MoveColumnRight)
+        );
+        assert_eq!(
+            keymap.action_for(KeyEvent::new(KeyCode::Char('p'), KeyModifiers::CONTROL)),
+            Some(Action::OpenProjectList)
+        );
+        assert_eq!(
+            keymap.action_for(press(KeyCode::Char('p'))),
+            Some(Action::PasteTask)
+        );
+    }
+
+    #[test]
+    fn test_normal_hints_follow_bindings() {
+        let navigate = &hints_for(&InputMode::Normal)[0];
+        assert_eq!(
+            KeyMap::default().hint_keys(navigate).as_deref(),
+            Some("h/j/k/l")
+        );
+
+        let mut overrides = HashMap::new();
+        overrides.insert("move_left".to_string(), KeyList::One("a".to_string()));
+        overrides.insert("add_task".to_string(), KeyList::One("i".to_string()));
+        let (keymap, _) = KeyMap::from_config(&overrides);
+        assert_eq!(keymap.hint_keys(navigate).as_deref(), Some("a/j/k/l"));
+        let add_task = hints_for(&InputMode::Normal)
+            .iter()
+            .find(|hint| hint.text == "add task")
+            .unwrap();
+        assert_eq!(keymap.hint_keys(add_task).as_deref(), Some("i"));
+    }
+
+    #[test]
+    fn test_user_overrides_and_errors() {
+        let mut overrides = HashMap::new();
+        overrides.insert("move_left".to_string(), KeyList::One("s".to_string()));
+        overrides.insert(
+            "move_down".to_string(),
+            KeyList::Many(vec!["d".to_string(), "NotAKey".to_string()]),
+        );
+        overrides.insert("fly_away".to_string(), KeyList::One("x".to_string()));
+
+        let (keymap, errors) = KeyMap::from_config(&overrides);
+
+        assert_eq!(
+            keymap.action_for(press(KeyCode::Char('s'))),
+            Some(Action::MoveLeft)
+        );
+        assert_eq!(keymap.action_for(press(KeyCode::Char('h'))), None);
+        // user binding wins over the default delete_task key, but is reported
+        assert_eq!(
+            keymap.action_for(press(KeyCode::Char('d'))),
+            Some(Action::MoveDown)
+        );
+        assert!(errors
+            .iter()
+            .any(|e| e.contains("unknown action 'fly_away'")));
+        assert!(errors.iter().any(|e| e.contains("invalid key 'NotAKey'")));
+        assert!(errors.iter().any(|e| e.contains("bound to both")));
+    }
+}
@@ -0,0 +1,14 @@
+// the board logic as a library, so it can be driven without a terminal (tests, other
+// tools); main.rs only runs the event loop on top of it
+pub mod app;
+pub mod board;
+pub mod cli;
+pub mod command;
+pub mod datetime;
+pub mod keymap;
+pub mod markdown;
+pub mod settings;
+pub mod storage;
+pub mod system_clipboard;
+pub mod theme;
+pub mod ui;
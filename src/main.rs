@@ -1,18 +1,26 @@
-mod app;
-mod board;
-mod storage;
-mod ui;
-
-use app::{App, InputMode};
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyModifiers},
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyModifiers,
+    },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
+use keymap::Action;
 use ratatui::{backend::CrosstermBackend, Terminal};
 use std::io;
+use std::time::{Duration, Instant};
+use tui_kanban::app::{self, App, InputMode};
+use tui_kanban::{cli, keymap, ui};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // handle command line subcommands without starting the TUI
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    let no_color = args.iter().any(|arg| arg == "--no-color");
+    args.retain(|arg| arg != "--no-color");
+    if let Some(code) = cli::run(&args) {
+        std::process::exit(code);
+    }
+
     // setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -22,7 +30,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // create app and run it
     let mut app = App::new();
+    app.no_color |= no_color;
     let res = run_app(&mut terminal, &mut app);
+    app.restore_clipboard();
+    app.flush_saves();
+    app.save_session();
 
     // restore terminal
     disable_raw_mode()?;
@@ -36,6 +48,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     if let Err(err) = res {
         println!("Error: {:?}", err);
     }
+    // the footer is gone by now, so a failed final save has to be reported here
+    if let Some(err) = &app.save_error {
+        eprintln!("Error: your last changes could not be saved: {}", err);
+        std::process::exit(1);
+    }
 
     Ok(())
 }
@@ -49,28 +66,59 @@ fn run_app<B: ratatui::backend::Backend>(
         // draw UI
         terminal.draw(|f| ui::draw(f, app))?;
 
+        // wake up now and then so flash messages expire and pending changes get
+        // written without a key press
+        app.expire_flash();
+        app.flush_due_saves(Instant::now());
+        if !event::poll(Duration::from_millis(250))? {
+            continue;
+        }
+
         // handle input
         if let Event::Key(key) = event::read()? {
-            // Handle Ctrl+P globally to open project list
-            if key.code == KeyCode::Char('p') && key.modifiers.contains(KeyModifiers::CONTROL) {
-                if app.input_mode == InputMode::Normal {
-                    app.open_project_list();
+            // control chords are only bound in normal mode, apart from the editing ones
+            if key.modifiers.contains(KeyModifiers::CONTROL) && app.input_mode != InputMode::Normal
+            {
+                if app.is_editing_text() {
+                    handle_editing_chord(app, key.code);
                 }
                 continue;
             }
 
             match app.input_mode {
-                InputMode::Normal => handle_normal_mode(app, key.code),
+                InputMode::Normal => handle_normal_mode(app, key),
+                InputMode::AddingTag => handle_adding_tag_mode(app, key.code),
                 InputMode::AddingTask
-                | InputMode::AddingTag
                 | InputMode::AddingColumn
-                | InputMode::RenamingColumn => handle_input_mode(app, key.code),
+                | InputMode::RenamingColumn
+                | InputMode::ColoringColumn
+                | InputMode::RenamingTagEverywhere
+                | InputMode::FilteringTag
+                | InputMode::TaggingColumn
+                | InputMode::UntaggingColumn
+                | InputMode::Command => handle_input_mode(app, key.code),
                 InputMode::ViewingTask => handle_viewing_task_mode(app, key.code),
-                InputMode::EditingTitle => handle_editing_title_mode(app, key.code),
+                InputMode::EditingTitle
+                | InputMode::EditingTag
+                | InputMode::EditingEstimate
+                | InputMode::EditingDueDate => handle_editing_title_mode(app, key.code),
                 InputMode::EditingDescription => handle_editing_description_mode(app, key.code),
                 InputMode::ViewingHelp => handle_viewing_help_mode(app, key.code),
                 InputMode::ProjectList => handle_project_list_mode(app, key.code),
-                InputMode::AddingProject => handle_adding_project_mode(app, key.code),
+                InputMode::AddingProject | InputMode::ColoringProject => {
+                    handle_adding_project_mode(app, key.code)
+                }
+                InputMode::PickingTemplate => handle_picking_template_mode(app, key.code),
+                InputMode::ConfirmDelete => handle_confirm_delete_mode(app, key.code),
+                InputMode::ViewingArchive => handle_viewing_archive_mode(app, key.code),
+                InputMode::ActivityLog => handle_activity_log_mode(app, key.code),
+                InputMode::TagLegend => handle_tag_legend_mode(app, key.code),
+                InputMode::Upcoming => handle_upcoming_mode(app, key.code),
+                InputMode::Minimap => handle_minimap_mode(app, key.code),
+                InputMode::PickingBlocker => handle_picking_blocker_mode(app, key.code),
+                InputMode::MovingTaskToProject => handle_moving_task_to_project_mode(app, key.code),
+                InputMode::MergingProject => handle_merging_project_mode(app, key.code),
+                InputMode::SearchingDescription => handle_searching_description_mode(app, key.code),
             }
         }
 
@@ -82,71 +130,114 @@ fn run_app<B: ratatui::backend::Backend>(
 }
 
 // handle keys in normal mode
-fn handle_normal_mode(app: &mut App, key: KeyCode) {
-    match key {
-        // Quit
-        KeyCode::Char('q') => app.should_quit = true,
-
-        // Navigation - vim keys
-        KeyCode::Char('h') => {
-            app.move_left();
-            app.update_scroll();
-        }
-        KeyCode::Char('j') => {
-            app.move_down();
-            app.update_scroll();
-        }
-        KeyCode::Char('k') => {
-            app.move_up();
-            app.update_scroll();
-        }
-        KeyCode::Char('l') => {
-            app.move_right();
-            app.update_scroll();
+fn handle_normal_mode(app: &mut App, key: KeyEvent) {
+    // digits build a count for the next motion (0 only continues a count)
+    if let KeyCode::Char(c) = key.code {
+        if key.modifiers.is_empty() || key.modifiers == KeyModifiers::SHIFT {
+            if let Some(digit) = c.to_digit(10) {
+                if digit != 0 || app.pending_count.is_some() {
+                    app.push_count_digit(digit);
+                    return;
+                }
+            }
         }
+    }
+    if key.code == KeyCode::Esc && app.pending_count.is_some() {
+        app.pending_count = None;
+        return;
+    }
+    if key.code == KeyCode::Esc && !app.marked.is_empty() {
+        app.clear_marks();
+        return;
+    }
 
-        // Navigation - arrow keys
-        KeyCode::Left => {
-            app.move_left();
+    // any action consumes the count, even if it doesn't use it
+    let count = app.pending_count;
+    let repeat = app.take_count();
+    if let Some(action) = app.keymap.action_for(key) {
+        run_action(app, action, count, repeat);
+    }
+}
+
+// perform a normal mode action, motions are repeated `repeat` times
+fn run_action(app: &mut App, action: Action, count: Option<usize>, repeat: usize) {
+    match action {
+        // Quit
+        Action::Quit => app.should_quit = true,
+
+        // Navigation
+        Action::MoveLeft => {
+            (0..repeat).for_each(|_| app.move_left());
             app.update_scroll();
         }
-        KeyCode::Down => {
-            app.move_down();
+        Action::MoveDown => {
+            (0..repeat).for_each(|_| app.move_down());
             app.update_scroll();
         }
-        KeyCode::Up => {
-            app.move_up();
+        Action::MoveUp => {
+            (0..repeat).for_each(|_| app.move_up());
             app.update_scroll();
         }
-        KeyCode::Right => {
-            app.move_right();
+        Action::MoveRight => {
+            (0..repeat).for_each(|_| app.move_right());
             app.update_scroll();
         }
+        Action::MoveToTop => app.move_to_top(),
+        Action::MoveToBottom => app.move_to_bottom(),
 
-        // Column Management (Shift+...)
-        KeyCode::Char('H')
-        | KeyCode::Char('L')
-        | KeyCode::Char('C')
-        | KeyCode::Char('R')
-        | KeyCode::Char('D') => match key {
-            KeyCode::Char('H') => app.move_column_left(),
-            KeyCode::Char('L') => app.move_column_right(),
-            KeyCode::Char('C') => app.start_adding_column(),
-            KeyCode::Char('R') => app.start_renaming_column(),
-            KeyCode::Char('D') => app.delete_column(),
-            _ => {}
-        },
+        // Column Management
+        Action::MoveColumnLeft => app.move_column_left(),
+        Action::MoveColumnRight => app.move_column_right(),
+        Action::AddColumn => app.start_adding_column(),
+        Action::RenameColumn => app.start_renaming_column(),
+        Action::DeleteColumn => app.delete_column(),
+        Action::ToggleCollapseColumn => app.toggle_collapse_column(),
+        Action::WidenColumn => app.resize_column(true),
+        Action::NarrowColumn => app.resize_column(false),
+        Action::OpenCommandLine => app.start_command(),
+        Action::OpenMinimap => app.open_minimap(),
+        Action::TagColumn => app.start_tagging_column(false),
+        Action::UntagColumn => app.start_tagging_column(true),
+        Action::ClearColumn => app.clear_column(),
+        Action::ToggleBacklogColumn => app.toggle_backlog_column(),
+        Action::PullFromBacklog => app.pull_from_backlog(),
+        Action::SetColumnColor => app.start_coloring_column(),
+        Action::SortColumn => app.sort_column_alphabetical(),
 
         // Actions
-        KeyCode::Enter => app.open_task(),
-        KeyCode::Char('a') => app.start_adding_task(),
-        KeyCode::Char('t') => app.start_adding_tag(),
-        KeyCode::Char('m') => app.move_task_forward(),
-        KeyCode::Char('n') => app.move_task_backward(),
-        KeyCode::Char('d') => app.delete_task(),
-        KeyCode::Char('?') => app.show_help(),
-
-        _ => {}
+        Action::OpenTask => app.open_task(),
+        Action::ToggleMark => app.toggle_mark(),
+        Action::ToggleExpandCard => app.toggle_expand_selected(),
+        Action::AddTask => app.start_adding_task(),
+        Action::AddTasks => app.start_adding_tasks(),
+        Action::AddTag => app.start_adding_tag(),
+        Action::RenameTagEverywhere => app.start_renaming_tag_everywhere(),
+        Action::MoveTaskForward => app.move_task_forward(),
+        Action::MoveTaskBackward => app.move_task_backward(),
+        Action::MoveTaskToColumn => match count {
+            // 3M sends the task to the third column
+            Some(column) => app.move_task_to_column(column - 1),
+            None => app.flash("Type a column number before M, e.g. 3M"),
+        },
+        Action::DeleteTask => app.delete_task(),
+        Action::CutTask => app.cut_task(),
+        Action::PasteTask => app.paste_task(),
+        Action::ExportMarkdown => app.export_markdown(),
+        Action::WriteStatus => app.write_status(),
+        Action::ShowHelp => app.show_help(),
+        Action::OpenProjectList => app.open_project_list(),
+        Action::NextProject => app.cycle_project(true),
+        Action::PreviousProject => app.cycle_project(false),
+        Action::ToggleConfirmDelete => app.toggle_confirm_delete(),
+        Action::ToggleTagFilter => app.toggle_tag_filter(),
+        Action::OpenTagLegend => app.open_tag_legend(),
+        Action::OpenUpcoming => app.open_upcoming(),
+        Action::RenameTask => app.start_renaming_task(),
+        Action::ToggleTheme => app.toggle_theme(),
+        Action::ArchiveTask => app.archive_task(),
+        Action::OpenArchive => app.open_archive(),
+        Action::OpenActivityLog => app.open_activity_log(),
+        Action::ToggleDescriptions => app.toggle_descriptions(),
     }
 }
 
@@ -161,11 +252,38 @@ fn handle_input_mode(app: &mut App, key: KeyCode) {
     }
 }
 
+// handle readline style Ctrl chords (and paste) while typing
+fn handle_editing_chord(app: &mut App, key: KeyCode) {
+    match key {
+        KeyCode::Char('w') | KeyCode::Backspace => app.delete_word_before_cursor(),
+        KeyCode::Char('u') => app.clear_input_line(),
+        KeyCode::Char('v') => app.paste_from_clipboard(),
+        _ => return,
+    }
+    // edited text no longer matches a picked suggestion
+    app.selected_suggestion = None;
+}
+
+// handle keys when adding a tag, arrows pick from the predefined tags
+fn handle_adding_tag_mode(app: &mut App, key: KeyCode) {
+    match key {
+        KeyCode::Down | KeyCode::Tab => app.suggestion_down(),
+        KeyCode::Up | KeyCode::BackTab => app.suggestion_up(),
+        KeyCode::Char(_) | KeyCode::Backspace => {
+            app.selected_suggestion = None;
+            handle_input_mode(app, key);
+        }
+        _ => handle_input_mode(app, key),
+    }
+}
+
 // handle keys when viewing task details
 fn handle_viewing_task_mode(app: &mut App, key: KeyCode) {
     use app::TaskField;
 
     match key {
+        // Esc first clears a description search
+        KeyCode::Esc if app.description_search.is_some() => app.clear_description_search(),
         KeyCode::Esc => app.close_view(),
         KeyCode::Tab => app.next_field(),
         KeyCode::Enter => {
@@ -173,21 +291,77 @@ fn handle_viewing_task_mode(app: &mut App, key: KeyCode) {
             match app.focused_field {
                 TaskField::Title => app.start_editing_title(),
                 TaskField::Description => app.start_editing_description(),
-                TaskField::Tags => {} // No action for tags, use numbers instead
+                TaskField::Tags => app.remove_selected_tag(),
             }
         }
-        KeyCode::Char(c @ '1'..='9') => {
-            // Remove tag by number (only when focused on tags)
-            if app.focused_field == TaskField::Tags {
-                let tag_index = (c as u8 - b'1') as usize;
-                app.remove_tag(tag_index);
-            }
+        // Remove tag by number (only when focused on tags)
+        KeyCode::Char(c @ '1'..='9') if app.focused_field == TaskField::Tags => {
+            let tag_index = (c as u8 - b'1') as usize;
+            app.remove_tag(tag_index);
+        }
+        KeyCode::Char('j') | KeyCode::Down if app.focused_field == TaskField::Tags => {
+            app.move_tag_down()
+        }
+        KeyCode::Char('k') | KeyCode::Up if app.focused_field == TaskField::Tags => {
+            app.move_tag_up()
+        }
+        KeyCode::Char('e') if app.focused_field == TaskField::Tags => app.start_editing_tag(),
+        KeyCode::Char('d') if app.focused_field == TaskField::Tags => app.remove_selected_tag(),
+        KeyCode::Char('j') | KeyCode::Down if app.focused_field == TaskField::Description => {
+            app.scroll_description(true)
+        }
+        KeyCode::Char('k') | KeyCode::Up if app.focused_field == TaskField::Description => {
+            app.scroll_description(false)
         }
+        KeyCode::Char('/') => app.start_description_search(),
+        KeyCode::Char('n') if app.description_search.is_some() => app.next_description_match(true),
+        KeyCode::Char('N') if app.description_search.is_some() => app.next_description_match(false),
+        KeyCode::Char('b') => app.start_picking_blocker(),
+        KeyCode::Char('p') => app.start_moving_task_to_project(),
+        KeyCode::Char('c') => app.cycle_task_color(),
+        KeyCode::Char('E') => app.start_editing_estimate(),
+        KeyCode::Char('D') => app.start_editing_due_date(),
         _ => {}
     }
 }
 
-// handle keys when editing title
+// handle keys while typing a description search
+fn handle_searching_description_mode(app: &mut App, key: KeyCode) {
+    match key {
+        KeyCode::Enter => app.submit_input(),
+        KeyCode::Esc => {
+            app.input_buffer.clear();
+            app.input_mode = InputMode::ViewingTask;
+        }
+        KeyCode::Backspace => app.input_backspace(),
+        KeyCode::Char(c) => app.input_char(c),
+        _ => {}
+    }
+}
+
+// handle keys when picking blockers for a task
+fn handle_picking_blocker_mode(app: &mut App, key: KeyCode) {
+    match key {
+        KeyCode::Esc => app.input_mode = InputMode::ViewingTask,
+        KeyCode::Char('j') | KeyCode::Down => app.move_blocker_down(),
+        KeyCode::Char('k') | KeyCode::Up => app.move_blocker_up(),
+        KeyCode::Enter | KeyCode::Char(' ') => app.toggle_blocker(),
+        _ => {}
+    }
+}
+
+// handle keys when picking a project to move the task to
+fn handle_moving_task_to_project_mode(app: &mut App, key: KeyCode) {
+    match key {
+        KeyCode::Esc => app.input_mode = InputMode::ViewingTask,
+        KeyCode::Char('j') | KeyCode::Down => app.move_project_down(),
+        KeyCode::Char('k') | KeyCode::Up => app.move_project_up(),
+        KeyCode::Enter => app.move_task_to_project(),
+        _ => {}
+    }
+}
+
+// handle keys when editing title (or a single tag, the estimate or the due date)
 fn handle_editing_title_mode(app: &mut App, key: KeyCode) {
     match key {
         KeyCode::Enter => {
@@ -195,9 +369,8 @@ fn handle_editing_title_mode(app: &mut App, key: KeyCode) {
             app.submit_input();
         }
         KeyCode::Esc => {
-            // Cancel editing and go back to viewing task
-            app.input_mode = InputMode::ViewingTask;
-            app.input_buffer.clear();
+            // Cancel editing and go back to viewing task (or the board)
+            app.finish_editing_title();
         }
         KeyCode::Backspace => app.input_backspace(),
         KeyCode::Char(c) => app.input_char(c),
@@ -217,6 +390,13 @@ fn handle_editing_description_mode(app: &mut App, key: KeyCode) {
             app.submit_input();
         }
         KeyCode::Backspace => app.input_backspace(),
+        KeyCode::Delete => app.input_delete(),
+        KeyCode::Left => app.cursor_left(),
+        KeyCode::Right => app.cursor_right(),
+        KeyCode::Up => app.cursor_up(),
+        KeyCode::Down => app.cursor_down(),
+        KeyCode::Home => app.cursor_line_start(),
+        KeyCode::End => app.cursor_line_end(),
         KeyCode::Char(c) => app.input_char(c),
         _ => {}
     }
@@ -226,6 +406,26 @@ fn handle_editing_description_mode(app: &mut App, key: KeyCode) {
 fn handle_viewing_help_mode(app: &mut App, key: KeyCode) {
     match key {
         KeyCode::Esc | KeyCode::Char('?') => app.close_view(),
+        KeyCode::Char('j') | KeyCode::Down => app.scroll_help_down(1),
+        KeyCode::Char('k') | KeyCode::Up => app.scroll_help_up(1),
+        KeyCode::PageDown | KeyCode::Char(' ') => app.scroll_help_down(app.help_page),
+        KeyCode::PageUp => app.scroll_help_up(app.help_page),
+        KeyCode::Char('g') | KeyCode::Home => app.scroll_help_up(usize::MAX),
+        KeyCode::Char('G') | KeyCode::End => app.scroll_help_down(usize::MAX),
+        _ => {}
+    }
+}
+
+// handle keys in the activity log, scrolled like the help
+fn handle_activity_log_mode(app: &mut App, key: KeyCode) {
+    match key {
+        KeyCode::Esc | KeyCode::Char('J') => app.close_view(),
+        KeyCode::Char('j') | KeyCode::Down => app.scroll_log_down(1),
+        KeyCode::Char('k') | KeyCode::Up => app.scroll_log_up(1),
+        KeyCode::PageDown | KeyCode::Char(' ') => app.scroll_log_down(app.log_page),
+        KeyCode::PageUp => app.scroll_log_up(app.log_page),
+        KeyCode::Char('g') | KeyCode::Home => app.scroll_log_up(usize::MAX),
+        KeyCode::Char('G') | KeyCode::End => app.scroll_log_down(usize::MAX),
         _ => {}
     }
 }
@@ -238,12 +438,71 @@ fn handle_project_list_mode(app: &mut App, key: KeyCode) {
         KeyCode::Char('k') | KeyCode::Up => app.move_project_up(),
         KeyCode::Enter => app.select_project(),
         KeyCode::Char('a') => app.start_adding_project(),
+        KeyCode::Char('c') => app.start_coloring_project(),
         KeyCode::Char('d') => app.delete_project(),
+        KeyCode::Char('y') => app.duplicate_project(),
+        KeyCode::Char('M') => app.start_merging_project(),
+        _ => {}
+    }
+}
+
+// handle keys while picking the project to merge into
+fn handle_merging_project_mode(app: &mut App, key: KeyCode) {
+    match key {
+        KeyCode::Esc => app.cancel_merging_project(),
+        KeyCode::Char('j') | KeyCode::Down => app.move_project_down(),
+        KeyCode::Char('k') | KeyCode::Up => app.move_project_up(),
+        KeyCode::Enter => app.merge_selected_project(),
         _ => {}
     }
 }
 
-// handle keys when adding project
+// handle keys in archive view
+fn handle_viewing_archive_mode(app: &mut App, key: KeyCode) {
+    match key {
+        KeyCode::Esc => app.close_view(),
+        KeyCode::Char('j') | KeyCode::Down => app.move_archive_down(),
+        KeyCode::Char('k') | KeyCode::Up => app.move_archive_up(),
+        KeyCode::Char('r') | KeyCode::Enter => app.restore_archived_task(),
+        _ => {}
+    }
+}
+
+// handle keys in the tag legend
+fn handle_tag_legend_mode(app: &mut App, key: KeyCode) {
+    match key {
+        KeyCode::Esc | KeyCode::Char('T') => app.close_view(),
+        KeyCode::Char('j') | KeyCode::Down => app.move_legend_down(),
+        KeyCode::Char('k') | KeyCode::Up => app.move_legend_up(),
+        KeyCode::Enter => app.filter_by_legend_tag(),
+        _ => {}
+    }
+}
+
+// handle keys in the upcoming view
+fn handle_upcoming_mode(app: &mut App, key: KeyCode) {
+    match key {
+        KeyCode::Esc | KeyCode::Char('U') => app.close_view(),
+        KeyCode::Char('j') | KeyCode::Down => app.move_upcoming_down(),
+        KeyCode::Char('k') | KeyCode::Up => app.move_upcoming_up(),
+        KeyCode::Enter => app.jump_to_upcoming(),
+        _ => {}
+    }
+}
+
+// handle keys in the board overview, moving the real selection
+fn handle_minimap_mode(app: &mut App, key: KeyCode) {
+    match key {
+        KeyCode::Esc | KeyCode::Enter | KeyCode::Char('O') => app.close_view(),
+        KeyCode::Char('h') | KeyCode::Left => app.move_left(),
+        KeyCode::Char('j') | KeyCode::Down => app.move_down(),
+        KeyCode::Char('k') | KeyCode::Up => app.move_up(),
+        KeyCode::Char('l') | KeyCode::Right => app.move_right(),
+        _ => {}
+    }
+}
+
+// handle keys when adding a project or typing its color
 fn handle_adding_project_mode(app: &mut App, key: KeyCode) {
     match key {
         KeyCode::Enter => app.submit_input(),
@@ -256,3 +515,26 @@ fn handle_adding_project_mode(app: &mut App, key: KeyCode) {
         _ => {}
     }
 }
+
+// handle keys when picking the columns of a new project
+fn handle_picking_template_mode(app: &mut App, key: KeyCode) {
+    match key {
+        KeyCode::Enter => app.submit_input(),
+        KeyCode::Esc => {
+            app.input_mode = InputMode::ProjectList;
+            app.input_buffer.clear();
+        }
+        KeyCode::Char('j') | KeyCode::Down => app.template_down(),
+        KeyCode::Char('k') | KeyCode::Up => app.template_up(),
+        _ => {}
+    }
+}
+
+// handle keys while waiting for delete confirmation
+fn handle_confirm_delete_mode(app: &mut App, key: KeyCode) {
+    match key {
+        KeyCode::Char('y') | KeyCode::Char('Y') => app.confirm_pending_delete(),
+        KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => app.cancel_pending_delete(),
+        _ => {}
+    }
+}
@@ -0,0 +1,136 @@
+use crate::theme::Theme;
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span, Text},
+};
+
+// render a description with basic markdown: # headings, -/*/+ bullets, **bold** and *italic*.
+// one output line per input line so scrolling by description line still lines up, and
+// anything that doesn't parse cleanly is shown as typed
+pub fn render(text: &str, theme: &Theme) -> Text<'static> {
+    Text::from(
+        text.split('\n')
+            .map(|line| render_line(line, theme))
+            .collect::<Vec<_>>(),
+    )
+}
+
+fn render_line(line: &str, theme: &Theme) -> Line<'static> {
+    let trimmed = line.trim_start();
+    let indent = &line[..line.len() - trimmed.len()];
+
+    let level = trimmed.chars().take_while(|&c| c == '#').count();
+    if (1..=6).contains(&level) && trimmed[level..].starts_with(' ') {
+        let mut style = Style::default()
+            .fg(theme.accent)
+            .add_modifier(Modifier::BOLD);
+        if level == 1 {
+            style = style.add_modifier(Modifier::UNDERLINED);
+        }
+        return Line::from(inline(trimmed[level..].trim(), style));
+    }
+
+    for marker in ["- ", "* ", "+ "] {
+        if let Some(item) = trimmed.strip_prefix(marker) {
+            let mut spans = vec![Span::raw(format!("{}• ", indent))];
+            spans.extend(inline(item, Style::default()));
+            return Line::from(spans);
+        }
+    }
+
+    Line::from(inline(line, Style::default()))
+}
+
+// split a line into plain, **bold** and *italic* spans on top of the base style
+fn inline(text: &str, base: Style) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    let mut plain = String::new();
+    let mut rest = text;
+    while let Some(star) = rest.find('*') {
+        let (marker, modifier) = if rest[star..].starts_with("**") {
+            ("**", Modifier::BOLD)
+        } else {
+            ("*", Modifier::ITALIC)
+        };
+        let after = &rest[star + marker.len()..];
+        match closing(after, marker) {
+            Some(end) => {
+                plain.push_str(&rest[..star]);
+                if !plain.is_empty() {
+                    spans.push(Span::styled(std::mem::take(&mut plain), base));
+                }
+                spans.push(Span::styled(
+                    after[..end].to_string(),
+                    base.add_modifier(modifier),
+                ));
+                rest = &after[end + marker.len()..];
+            }
+            None => {
+                // unmatched marker, keep it as text
+                plain.push_str(&rest[..star + marker.len()]);
+                rest = after;
+            }
+        }
+    }
+    plain.push_str(rest);
+    if !plain.is_empty() || spans.is_empty() {
+        spans.push(Span::styled(plain, base));
+    }
+    spans
+}
+
+// end of the emphasized text, which must not be empty or padded with spaces
+// (so "2 * 3 * 4" stays as it is)
+fn closing(text: &str, marker: &str) -> Option<usize> {
+    if text.starts_with(char::is_whitespace) {
+        return None;
+    }
+    let end = text.find(marker)?;
+    let inner = &text[..end];
+    (!inner.is_empty() && !inner.ends_with(char::is_whitespace)).then_some(end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plain(line: &Line) -> String {
+        line.spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect()
+    }
+
+    #[test]
+    fn test_render_markdown() {
+        let theme = Theme::DARK;
+        let text = render(
+            "# Plan\n- **fix** the *parser*\n  * nested\nplain 2 * 3 * 4\n**unclosed and *open",
+            &theme,
+        );
+        let lines: Vec<String> = text.lines.iter().map(plain).collect();
+        assert_eq!(
+            lines,
+            vec![
+                "Plan",
+                "• fix the parser",
+                "  • nested",
+                "plain 2 * 3 * 4",
+                "**unclosed and *open",
+            ]
+        );
+
+        let heading = &text.lines[0].spans[0];
+        assert!(heading.style.add_modifier.contains(Modifier::BOLD));
+        let bullet = &text.lines[1].spans;
+        assert!(bullet[1].style.add_modifier.contains(Modifier::BOLD));
+        assert!(bullet[3].style.add_modifier.contains(Modifier::ITALIC));
+    }
+
+    #[test]
+    fn test_render_keeps_line_count() {
+        let theme = Theme::DARK;
+        assert_eq!(render("", &theme).lines.len(), 1);
+        assert_eq!(render("a\n\n#\n-", &theme).lines.len(), 4);
+    }
+}
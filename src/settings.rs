@@ -0,0 +1,136 @@
+use crate::board;
+use crate::datetime;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+pub const BORDER_TYPES: [&str; 4] = ["plain", "rounded", "double", "thick"];
+
+// user preferences stored in settings.json
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct Settings {
+    pub theme: String,
+    pub confirm_delete: bool,
+    pub auto_archive_days: Option<u32>, // archive done tasks untouched this long, off when None
+    pub default_columns: Vec<(String, String)>, // (id, name) of the columns new projects start with
+    pub border_type: String,            // plain, rounded, double or thick
+    pub date_format: String,            // strftime-style, see datetime::is_valid_format
+    pub show_descriptions: bool,        // cards show the first line of their description
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            theme: "dark".to_string(),
+            confirm_delete: true,
+            auto_archive_days: None,
+            default_columns: board::builtin_template_columns(0),
+            border_type: "plain".to_string(),
+            date_format: datetime::DEFAULT_DATE_FORMAT.to_string(),
+            show_descriptions: false,
+        }
+    }
+}
+
+impl Settings {
+    // fall back to the built-in defaults for invalid values, returning what was replaced
+    // (default columns that are empty, unnamed or share an id, unknown border types and
+    // date formats)
+    pub fn validate(&mut self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        let mut ids = HashSet::new();
+        let valid = !self.default_columns.is_empty()
+            && self.default_columns.iter().all(|(id, name)| {
+                !id.trim().is_empty() && !name.trim().is_empty() && ids.insert(id.as_str())
+            });
+        if !valid {
+            warnings.push("invalid default_columns, using the built-in ones".to_string());
+            self.default_columns = Settings::default().default_columns;
+        }
+
+        self.border_type = self.border_type.trim().to_lowercase();
+        if !BORDER_TYPES.contains(&self.border_type.as_str()) {
+            warnings.push(format!("unknown border_type '{}'", self.border_type));
+            self.border_type = Settings::default().border_type;
+        }
+
+        if !datetime::is_valid_format(&self.date_format) {
+            warnings.push(format!(
+                "invalid date_format '{}', using {}",
+                self.date_format,
+                datetime::DEFAULT_DATE_FORMAT
+            ));
+            self.date_format = Settings::default().date_format;
+        }
+        warnings
+    }
+}
+
+// last position on the board, stored in session.json (separate from shared data)
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct Session {
+    pub current_project: usize,
+    pub selected_column: usize,
+    pub selected_index: usize,
+    pub scroll_offset: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_default_columns() {
+        let column = |id: &str, name: &str| (id.to_string(), name.to_string());
+        // the fallback is always the shipped template, whatever default_columns was loaded
+        let builtin = board::builtin_template_columns(0);
+        assert_eq!(Settings::default().default_columns, builtin);
+
+        let mut settings = Settings {
+            default_columns: vec![column("todo", "To Do"), column("done", "Done")],
+            ..Settings::default()
+        };
+        settings.validate();
+        assert_eq!(settings.default_columns.len(), 2);
+
+        settings.default_columns = vec![column("a", "One"), column("a", "Two")];
+        settings.validate();
+        assert_eq!(settings.default_columns, builtin);
+
+        settings.default_columns = Vec::new();
+        settings.validate();
+        assert_eq!(settings.default_columns, builtin);
+    }
+
+    #[test]
+    fn test_validate_border_type() {
+        let mut settings = Settings {
+            border_type: " Rounded".to_string(),
+            ..Settings::default()
+        };
+        settings.validate();
+        assert_eq!(settings.border_type, "rounded");
+
+        settings.border_type = "dotted".to_string();
+        assert_eq!(settings.validate(), vec!["unknown border_type 'dotted'"]);
+        assert_eq!(settings.border_type, "plain");
+    }
+
+    #[test]
+    fn test_validate_date_format() {
+        let mut settings = Settings {
+            date_format: "%d.%m.%Y".to_string(),
+            ..Settings::default()
+        };
+        assert!(settings.validate().is_empty());
+        assert_eq!(settings.date_format, "%d.%m.%Y");
+
+        settings.date_format = "%d %Q".to_string();
+        assert_eq!(
+            settings.validate(),
+            vec!["invalid date_format '%d %Q', using %Y-%m-%d"]
+        );
+        assert_eq!(settings.date_format, "%Y-%m-%d");
+    }
+}
@@ -1,8 +1,13 @@
 use crate::board::{Board, BoardColumn, Project, Task};
+use crate::keymap::KeyList;
+use crate::settings::{Session, Settings};
 use directories::ProjectDirs;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
 
 // This struct represents the old Board structure for migration purposes
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -29,21 +34,37 @@ impl From<LegacyBoard> for Board {
                     id: "todo".to_string(),
                     name: "To Do".to_string(),
                     tasks: legacy_board.todo,
+                    collapsed: false,
+                    color: None,
+                    width: None,
+                    backlog: false,
                 },
                 BoardColumn {
                     id: "in_progress".to_string(),
                     name: "In Progress".to_string(),
                     tasks: legacy_board.in_progress,
+                    collapsed: false,
+                    color: None,
+                    width: None,
+                    backlog: false,
                 },
                 BoardColumn {
                     id: "testing".to_string(),
                     name: "Testing".to_string(),
                     tasks: legacy_board.testing,
+                    collapsed: false,
+                    color: None,
+                    width: None,
+                    backlog: false,
                 },
                 BoardColumn {
                     id: "done".to_string(),
                     name: "Done".to_string(),
                     tasks: legacy_board.done,
+                    collapsed: false,
+                    color: None,
+                    width: None,
+                    backlog: false,
                 },
             ],
         }
@@ -56,25 +77,29 @@ impl From<LegacyProject> for Project {
         Project {
             name: legacy_project.name,
             board: legacy_project.board.into(), // Use the From<LegacyBoard> impl
+            archived: Vec::new(),
+            color: None,
+            log: Vec::new(),
         }
     }
 }
 
-// get path to config file
-fn get_config_path() -> PathBuf {
+// get config directory (created on first write)
+fn get_config_dir() -> PathBuf {
     // ProjectDirs auto find config
     if let Some(proj_dirs) = ProjectDirs::from("", "", "tui-kanban") {
-        let config_dir = proj_dirs.config_dir();
-        // folder exists?
-
-        fs::create_dir_all(config_dir).ok();
-        config_dir.join("projects.json")
+        proj_dirs.config_dir().to_path_buf()
     } else {
         // fallback
-        PathBuf::from("projects.json")
+        PathBuf::from(".")
     }
 }
 
+// get path to config file
+fn get_config_path() -> PathBuf {
+    get_config_dir().join("projects.json")
+}
+
 // get old omarchy-kanban config path for migration
 fn get_old_omarchy_config_path() -> PathBuf {
     if let Some(proj_dirs) = ProjectDirs::from("", "", "omarchy-kanban") {
@@ -95,58 +120,745 @@ fn get_old_board_path() -> PathBuf {
     }
 }
 
-/// saves projects to disc
-pub fn save_projects(projects: &[Project]) -> Result<(), Box<dyn std::error::Error>> {
-    let path = get_config_path();
+// projects.json and the older files it can be migrated from
+struct DataPaths {
+    projects: PathBuf,
+    old_projects: PathBuf,
+    old_boards: Vec<PathBuf>, // single-board files, first found wins
+}
+
+impl DataPaths {
+    fn current() -> Self {
+        Self {
+            projects: get_config_path(),
+            old_projects: get_old_omarchy_config_path(),
+            // early single-board builds also ran under the tui-kanban name
+            old_boards: vec![get_config_dir().join("board.json"), get_old_board_path()],
+        }
+    }
+}
+
+// get path for a markdown export of a project, next to projects.json
+fn get_export_path(project_name: &str) -> PathBuf {
+    let file_name: String = project_name
+        .chars()
+        .map(|c| if c == '/' || c == '\\' { '_' } else { c })
+        .collect();
+    get_config_path().with_file_name(format!("{}.md", file_name))
+}
+
+/// renders a project board as markdown (columns as headings, tasks as checkboxes)
+pub fn export_board_markdown(project: &Project) -> String {
+    let mut out = format!("# {}\n", project.name);
+
+    let done_idx = project.board.done_column_index();
+    for (i, column) in project.board.columns.iter().enumerate() {
+        out.push_str(&format!("\n## {}\n\n", column.name));
+        let checkbox = if Some(i) == done_idx { "[x]" } else { "[ ]" };
+
+        for task in &column.tasks {
+            out.push_str(&format!("- {} {}", checkbox, task.title));
+            for tag in &task.tags {
+                out.push_str(&format!(" `#{}`", tag));
+            }
+            out.push('\n');
+
+            // indent description lines so they stay inside the list item
+            for line in task.description.lines() {
+                if line.is_empty() {
+                    out.push('\n');
+                } else {
+                    out.push_str(&format!("  {}\n", line));
+                }
+            }
+        }
+    }
+
+    out
+}
+
+/// writes a markdown export of the project to disc and returns its path
+pub fn export_project_markdown(project: &Project) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let path = get_export_path(&project.name);
+    write_atomic(&path, &export_board_markdown(project))?;
+    Ok(path)
+}
+
+// board summary written to status.json for status bars (waybar, i3blocks, ...)
+#[derive(Serialize, Debug, PartialEq)]
+pub struct StatusSummary {
+    pub project: String,
+    pub total: usize,
+    pub done: usize,       // tasks in the last column
+    pub done_percent: u32, // rounded, 0 for an empty board
+    pub columns: Vec<ColumnStatus>,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+pub struct ColumnStatus {
+    pub name: String,
+    pub count: usize,
+}
+
+impl StatusSummary {
+    pub fn from_project(project: &Project) -> Self {
+        let (total, done) = project.board.progress();
+        let done_percent = if total == 0 {
+            0
+        } else {
+            (done as f64 / total as f64 * 100.0).round() as u32
+        };
+        Self {
+            project: project.name.clone(),
+            total,
+            done,
+            done_percent,
+            columns: project
+                .board
+                .columns
+                .iter()
+                .map(|col| ColumnStatus {
+                    name: col.name.clone(),
+                    count: col.tasks.len(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// writes the project summary to status.json and returns its path
+pub fn write_status_json(project: &Project) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let path = get_config_dir().join("status.json");
+    let json = serde_json::to_string_pretty(&StatusSummary::from_project(project))?;
+    write_atomic(&path, &json)?;
+    Ok(path)
+}
+
+// subset of a Trello board export, everything optional since exports vary
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct TrelloBoard {
+    name: String,
+    lists: Vec<TrelloList>,
+    cards: Vec<TrelloCard>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct TrelloList {
+    id: String,
+    name: String,
+    closed: bool,
+    pos: f64,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, rename_all = "camelCase")]
+struct TrelloCard {
+    name: String,
+    desc: String,
+    id_list: String,
+    closed: bool,
+    pos: f64,
+    labels: Vec<TrelloLabel>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct TrelloLabel {
+    name: String,
+    color: Option<String>,
+}
+
+/// converts a Trello board export into a project (lists become columns, cards become tasks)
+pub fn parse_trello(json: &str) -> Result<Project, Box<dyn std::error::Error>> {
+    let mut trello: TrelloBoard = serde_json::from_str(json)?;
+    trello.lists.retain(|list| !list.closed);
+    if trello.lists.is_empty() {
+        return Err("no open lists found in Trello export".into());
+    }
+    trello.lists.sort_by(|a, b| a.pos.total_cmp(&b.pos));
+    trello.cards.retain(|card| !card.closed);
+    trello.cards.sort_by(|a, b| a.pos.total_cmp(&b.pos));
+
+    let name = if trello.name.trim().is_empty() {
+        "Trello import".to_string()
+    } else {
+        trello.name
+    };
+    let mut project = Project::new(name);
+    // lists may share a name or have none, so ids are made unique one column at a time
+    project.board.columns.clear();
+    for list in &trello.lists {
+        let slug = if list.name.trim().is_empty() {
+            "list"
+        } else {
+            &list.name
+        };
+        let id = project.board.unique_column_id(slug);
+        project
+            .board
+            .columns
+            .push(BoardColumn::new(id, list.name.clone()));
+    }
+
+    for card in trello.cards {
+        // cards of archived or unknown lists are skipped
+        let Some(column) = trello.lists.iter().position(|list| list.id == card.id_list) else {
+            continue;
+        };
+        let mut task = Task::new(card.name);
+        task.description = card.desc;
+        for label in card.labels {
+            // unnamed labels only have a color
+            let tag = if label.name.trim().is_empty() {
+                label.color.unwrap_or_default()
+            } else {
+                label.name
+            };
+            task.add_tag(tag);
+        }
+        project.board.columns[column].tasks.push(task);
+    }
+
+    Ok(project)
+}
+
+/// reads a Trello JSON export from disc, see parse_trello
+pub fn import_trello(path: &Path) -> Result<Project, Box<dyn std::error::Error>> {
+    parse_trello(&fs::read_to_string(path)?)
+}
+
+// issues asked for per request, a shorter page means it was the last one
+const GITHUB_PAGE_SIZE: usize = 100;
+// stop after this many pages (10,000 issues) in case the API keeps answering
+const GITHUB_MAX_PAGES: usize = 100;
+
+// the fields we use from the GitHub issues API
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct GithubIssue {
+    title: String,
+    body: Option<String>,
+    labels: Vec<GithubLabel>,
+    pull_request: Option<serde_json::Value>, // only set on pull requests
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct GithubLabel {
+    name: String,
+}
+
+// error responses carry a message instead of a list of issues
+#[derive(Deserialize)]
+struct GithubError {
+    message: String,
+}
+
+/// converts one page of the GitHub issues API into tasks, skipping pull requests;
+/// also returns how many entries the page had, to know when to stop paging
+pub fn parse_github_issues(json: &str) -> Result<(Vec<Task>, usize), Box<dyn std::error::Error>> {
+    let issues: Vec<GithubIssue> = match serde_json::from_str(json) {
+        Ok(issues) => issues,
+        Err(err) => {
+            return Err(match serde_json::from_str::<GithubError>(json) {
+                Ok(error) => format!("GitHub API: {}", error.message).into(),
+                Err(_) => err.into(),
+            })
+        }
+    };
+    let count = issues.len();
+    let tasks = issues
+        .into_iter()
+        .filter(|issue| issue.pull_request.is_none())
+        .map(|issue| {
+            let mut task = Task::new(issue.title);
+            task.description = issue.body.unwrap_or_default().replace("\r\n", "\n");
+            for label in issue.labels {
+                task.add_tag(label.name);
+            }
+            task
+        })
+        .collect();
+    Ok((tasks, count))
+}
+
+// one page of open issues via curl, the token (if any) goes in through stdin so it
+// doesn't show up in the process list
+fn fetch_github_page(repo: &str, page: usize) -> Result<String, Box<dyn std::error::Error>> {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    let url = format!(
+        "https://api.github.com/repos/{}/issues?state=open&per_page={}&page={}",
+        repo, GITHUB_PAGE_SIZE, page
+    );
+    let mut child = Command::new("curl")
+        .args(["--silent", "--show-error", "--location"])
+        .args(["-H", "Accept: application/vnd.github+json"])
+        .args(["-H", "User-Agent: tui-kanban"])
+        .args(["-H", "@-", &url])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| format!("could not run curl: {}", err))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        if let Ok(token) = std::env::var("GITHUB_TOKEN") {
+            writeln!(stdin, "Authorization: Bearer {}", token.trim())?;
+        }
+    }
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr)
+            .trim()
+            .to_string()
+            .into());
+    }
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+/// fetches the open issues of an owner/repo GitHub repository into a new project,
+/// all in its first column (GITHUB_TOKEN is used when set, for private repos and rate limits)
+pub fn import_github(repo: &str) -> Result<Project, Box<dyn std::error::Error>> {
+    let valid = repo.split('/').count() == 2
+        && repo.split('/').all(|part| {
+            // "." and ".." would make curl resolve a different API path
+            !part.is_empty()
+                && part != "."
+                && part != ".."
+                && part
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || "-_.".contains(c))
+        });
+    if !valid {
+        return Err(format!("expected owner/repo, got '{}'", repo).into());
+    }
+
+    let mut project = Project::new(repo.to_string());
+    for page in 1..=GITHUB_MAX_PAGES {
+        let (tasks, count) = parse_github_issues(&fetch_github_page(repo, page)?)?;
+        project.board.columns[0].tasks.extend(tasks);
+        if count < GITHUB_PAGE_SIZE {
+            break;
+        }
+    }
+    Ok(project)
+}
+
+/// reads user tag colors (tag name -> color string) from tags.json, empty if absent
+pub fn load_tag_colors() -> HashMap<String, String> {
+    let path = get_config_dir().join("tags.json");
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// reads keybinding overrides (action name -> key or keys) from keybindings.json
+pub fn load_keybindings() -> Result<HashMap<String, KeyList>, String> {
+    let path = get_config_dir().join("keybindings.json");
+    match fs::read_to_string(path) {
+        Ok(content) => serde_json::from_str(&content).map_err(|err| err.to_string()),
+        Err(_) => Ok(HashMap::new()),
+    }
+}
+
+/// reads user preferences from settings.json, defaults if absent or invalid,
+/// along with warnings about values that were replaced
+pub fn load_settings() -> (Settings, Vec<String>) {
+    let path = get_config_dir().join("settings.json");
+    let mut settings: Settings = fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default();
+    let warnings = settings.validate();
+    (settings, warnings)
+}
+
+/// project name from a .tui-kanban file in the current directory, if there is one
+pub fn load_directory_project() -> Option<String> {
+    let path = std::env::current_dir().ok()?.join(".tui-kanban");
+    let content = fs::read_to_string(path).ok()?;
+    let name = content.lines().next()?.trim();
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+/// reads the last board position from session.json, defaults if absent
+pub fn load_session() -> Session {
+    let path = get_config_dir().join("session.json");
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// saves the board position to disc
+pub fn save_session(session: &Session) -> Result<(), Box<dyn std::error::Error>> {
+    let path = get_config_dir().join("session.json");
+    let json = serde_json::to_string_pretty(session)?;
+    write_atomic(&path, &json)?;
+    Ok(())
+}
+
+// write via a temp file in the same directory and rename it over the target,
+// so a crash mid-write never leaves a truncated file behind
+fn write_atomic(path: &Path, contents: &str) -> io::Result<()> {
+    let mut tmp_name = path.file_name().unwrap_or_default().to_os_string();
+    tmp_name.push(".tmp");
+    let tmp_path = path.with_file_name(tmp_name);
+
+    // folder exists?
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&tmp_path, contents)?;
+    fs::rename(&tmp_path, path)
+}
+
+// how many timestamped backups of projects.json to keep
+const MAX_BACKUPS: usize = 10;
+
+// copy a file into backup_dir as <stem>-<millis>.json and prune all but the newest `keep`
+fn backup_file(path: &Path, backup_dir: &Path, keep: usize) -> io::Result<()> {
+    if !path.exists() {
+        return Ok(()); // Nothing to back up yet
+    }
+    fs::create_dir_all(backup_dir)?;
+
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis());
+    fs::copy(
+        path,
+        backup_dir.join(format!("{}-{:013}.json", stem, millis)),
+    )?;
+
+    // zero padded timestamps sort chronologically by name
+    let prefix = format!("{}-", stem);
+    let mut backups: Vec<PathBuf> = fs::read_dir(backup_dir)?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|p| {
+            p.file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.starts_with(&prefix) && n.ends_with(".json"))
+        })
+        .collect();
+    backups.sort();
+    let excess = backups.len().saturating_sub(keep);
+    for old in &backups[..excess] {
+        fs::remove_file(old)?;
+    }
+    Ok(())
+}
+
+/// copies the current projects.json into backups/ before it gets overwritten
+pub fn backup_projects() -> Result<(), Box<dyn std::error::Error>> {
+    backup_file(
+        &get_config_path(),
+        &get_config_dir().join("backups"),
+        MAX_BACKUPS,
+    )?;
+    Ok(())
+}
+
+/// saves user preferences to disc
+pub fn save_settings(settings: &Settings) -> Result<(), Box<dyn std::error::Error>> {
+    let path = get_config_dir().join("settings.json");
+    let json = serde_json::to_string_pretty(settings)?;
+    write_atomic(&path, &json)?;
+    Ok(())
+}
+
+/// saves projects to disc, storing the current card positions as their order
+pub fn save_projects(projects: &mut [Project]) -> Result<(), Box<dyn std::error::Error>> {
+    save_projects_to(&get_config_path(), projects)
+}
+
+fn save_projects_to(
+    path: &Path,
+    projects: &mut [Project],
+) -> Result<(), Box<dyn std::error::Error>> {
+    for project in projects.iter_mut() {
+        project.board.renumber();
+    }
     let json = serde_json::to_string_pretty(projects)?;
-    fs::write(path, json)?;
+    write_atomic(path, &json)?;
     Ok(())
 }
 
-// read projects
-pub fn load_projects() -> Vec<Project> {
-    let path = get_config_path();
-    let old_omarchy_path = get_old_omarchy_config_path();
-    let old_board_path = get_old_board_path();
+// read projects without writing anything, also reporting whether they were migrated
+fn read_projects(paths: &DataPaths) -> (Vec<Project>, bool) {
+    let path = &paths.projects;
+    let old_omarchy_path = &paths.old_projects;
 
     // 1. Try to load projects in the NEW format (main path)
     if path.exists() {
-        if let Ok(content) = fs::read_to_string(&path) {
+        if let Ok(content) = fs::read_to_string(path) {
             if let Ok(projects) = serde_json::from_str::<Vec<Project>>(&content) {
-                return projects;
+                return (projects, false);
             }
         }
     }
 
     // 2. Try to migrate from old omarchy-kanban projects.json (intermediate format)
     if old_omarchy_path.exists() {
-        if let Ok(content) = fs::read_to_string(&old_omarchy_path) {
+        if let Ok(content) = fs::read_to_string(old_omarchy_path) {
             if let Ok(legacy_projects) = serde_json::from_str::<Vec<LegacyProject>>(&content) {
                 let projects: Vec<Project> = legacy_projects.into_iter().map(Into::into).collect();
-                // Save to new location in new format
-                let _ = save_projects(&projects);
-                return projects;
+                return (projects, true);
             }
         }
     }
 
     // 3. Try to migrate from old board.json (even older format)
-    if old_board_path.exists() {
-        if let Ok(content) = fs::read_to_string(&old_board_path) {
+    for old_board_path in paths.old_boards.iter().filter(|p| p.exists()) {
+        if let Ok(content) = fs::read_to_string(old_board_path) {
             if let Ok(legacy_board) = serde_json::from_str::<LegacyBoard>(&content) {
                 let new_board: Board = legacy_board.into();
                 let default_project = Project {
                     name: "Default".to_string(),
                     board: new_board,
+                    archived: Vec::new(),
+                    color: None,
+                    log: Vec::new(),
                 };
-                // Save as new format
-                let _ = save_projects(&vec![default_project.clone()]);
-                return vec![default_project];
+                return (vec![default_project], true);
             }
         }
     }
 
     // 4. Fallback: incase non exist - return default project in NEW format
     let default_project = Project::new("Default".to_string());
-    vec![default_project]
+    (vec![default_project], false)
+}
+
+// read projects, saving migrated data to the new location in the new format
+pub fn load_projects() -> Vec<Project> {
+    load_projects_from(&DataPaths::current())
+}
+
+fn load_projects_from(paths: &DataPaths) -> Vec<Project> {
+    let (mut projects, migrated) = read_projects(paths);
+    for project in &mut projects {
+        project.board.ensure_column();
+        project.board.sort_by_order();
+    }
+    if migrated {
+        let _ = save_projects_to(&paths.projects, &mut projects);
+    }
+    projects
+}
+
+// read projects without touching anything on disk (no migration saves)
+pub fn load_projects_readonly() -> Vec<Project> {
+    let mut projects = read_projects(&DataPaths::current()).0;
+    for project in &mut projects {
+        project.board.ensure_column();
+        project.board.sort_by_order();
+    }
+    projects
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_atomic_replaces_file() {
+        let dir = std::env::temp_dir().join(format!("tui-kanban-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("projects.json");
+
+        fs::write(&path, "old").unwrap();
+        write_atomic(&path, "new").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+        assert!(!dir.join("projects.json.tmp").exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_migrate_board_json_from_new_config_dir() {
+        let dir = std::env::temp_dir().join(format!("tui-kanban-legacy-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let paths = DataPaths {
+            projects: dir.join("projects.json"),
+            old_projects: dir.join("omarchy").join("projects.json"),
+            old_boards: vec![
+                dir.join("board.json"),
+                dir.join("omarchy").join("board.json"),
+            ],
+        };
+        fs::write(
+            dir.join("board.json"),
+            r#"{"todo": [{"title": "Legacy task", "tags": [], "description": ""}],
+                "in_progress": [], "testing": [], "done": []}"#,
+        )
+        .unwrap();
+
+        let projects = load_projects_from(&paths);
+        assert_eq!(projects.len(), 1);
+        assert_eq!(projects[0].name, "Default");
+        assert_eq!(projects[0].board.columns[0].tasks[0].title, "Legacy task");
+
+        // re-saved as projects.json, which wins from now on
+        let saved = fs::read_to_string(&paths.projects).unwrap();
+        let reloaded: Vec<Project> = serde_json::from_str(&saved).unwrap();
+        assert_eq!(reloaded[0].board.columns.len(), 4);
+        assert_eq!(reloaded[0].board.columns[0].tasks[0].title, "Legacy task");
+        assert!(!read_projects(&paths).1);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_backup_file_prunes_old_backups() {
+        let dir = std::env::temp_dir().join(format!("tui-kanban-backup-{}", std::process::id()));
+        let backup_dir = dir.join("backups");
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("projects.json");
+
+        // Missing file: nothing to back up
+        backup_file(&path, &backup_dir, 3).unwrap();
+        assert!(!backup_dir.exists());
+
+        fs::write(&path, "[]").unwrap();
+        for _ in 0..5 {
+            backup_file(&path, &backup_dir, 3).unwrap();
+            std::thread::sleep(std::time::Duration::from_millis(2));
+        }
+        assert_eq!(fs::read_dir(&backup_dir).unwrap().count(), 3);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_parse_trello() {
+        let json = r#"{
+            "name": "Website",
+            "lists": [
+                {"id": "l2", "name": "Doing", "pos": 2},
+                {"id": "l1", "name": "To Do", "pos": 1},
+                {"id": "l3", "name": "Old", "closed": true}
+            ],
+            "cards": [
+                {"name": "Fix header", "desc": "It wobbles", "idList": "l1",
+                 "labels": [{"name": "Bug", "color": "red"}, {"name": "", "color": "green"}]},
+                {"name": "Gone", "idList": "l1", "closed": true},
+                {"name": "In old list", "idList": "l3"},
+                {"idList": "l2"}
+            ]
+        }"#;
+
+        let project = parse_trello(json).unwrap();
+        assert_eq!(project.name, "Website");
+        let columns = &project.board.columns;
+        assert_eq!(columns.len(), 2);
+        assert_eq!(
+            (columns[0].id.as_str(), columns[0].name.as_str()),
+            ("to_do", "To Do")
+        );
+        assert_eq!(columns[0].tasks.len(), 1);
+        assert_eq!(columns[0].tasks[0].title, "Fix header");
+        assert_eq!(columns[0].tasks[0].description, "It wobbles");
+        assert_eq!(columns[0].tasks[0].tags, vec!["bug", "green"]);
+        assert_eq!(columns[1].tasks.len(), 1); // card without a name still imports
+
+        assert!(parse_trello(r#"{"lists": []}"#).is_err());
+    }
+
+    #[test]
+    fn test_parse_trello_unique_column_ids() {
+        let json = r#"{
+            "lists": [
+                {"id": "l1", "name": "Ideas", "pos": 1},
+                {"id": "l2", "name": "ideas", "pos": 2},
+                {"id": "l3", "name": "", "pos": 3},
+                {"id": "l4", "name": " ", "pos": 4}
+            ]
+        }"#;
+
+        let project = parse_trello(json).unwrap();
+        let ids: Vec<&str> = project
+            .board
+            .columns
+            .iter()
+            .map(|col| col.id.as_str())
+            .collect();
+        assert_eq!(ids, ["ideas", "ideas_2", "list", "list_2"]);
+    }
+
+    #[test]
+    fn test_parse_github_issues() {
+        let json = r#"[
+            {"title": "Crash on start", "body": "Steps:\r\n1. open", "labels": [{"name": "bug"}, {"name": "P1"}]},
+            {"title": "Add feature", "body": null, "labels": [], "pull_request": {"url": "..."}},
+            {"title": "No body"}
+        ]"#;
+        let (tasks, count) = parse_github_issues(json).unwrap();
+        assert_eq!(count, 3);
+        assert_eq!(tasks.len(), 2); // the pull request is skipped
+        assert_eq!(tasks[0].title, "Crash on start");
+        assert_eq!(tasks[0].description, "Steps:\n1. open");
+        assert_eq!(tasks[0].tags, vec!["bug", "p1"]);
+        assert_eq!(tasks[1].description, "");
+
+        let err = parse_github_issues(r#"{"message": "Not Found"}"#).unwrap_err();
+        assert_eq!(err.to_string(), "GitHub API: Not Found");
+        assert!(parse_github_issues("<html>").is_err());
+        assert!(import_github("not-a-repo").is_err());
+        for repo in ["../x", "owner/..", "./repo", "owner/."] {
+            let err = import_github(repo).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!("expected owner/repo, got '{}'", repo)
+            );
+        }
+    }
+
+    #[test]
+    fn test_status_summary() {
+        let mut project = Project::new("Work".to_string());
+        project.board.columns[0]
+            .tasks
+            .push(Task::new("Open".to_string()));
+        project.board.columns[3]
+            .tasks
+            .push(Task::new("Shipped".to_string()));
+        project.board.columns[3]
+            .tasks
+            .push(Task::new("Also shipped".to_string()));
+
+        let summary = StatusSummary::from_project(&project);
+        assert_eq!(
+            (summary.total, summary.done, summary.done_percent),
+            (3, 2, 67)
+        );
+        assert_eq!(summary.columns[0].name, "To Do");
+        assert_eq!(summary.columns[0].count, 1);
+
+        let json = serde_json::to_value(&summary).unwrap();
+        assert_eq!(json["columns"][3]["count"], 2);
+    }
+
+    #[test]
+    fn test_export_board_markdown() {
+        let mut project = Project::new("Work".to_string());
+        let mut task = Task::new("Fix parser".to_string());
+        task.add_tag("bug".to_string());
+        task.description = "First line\nSecond line".to_string();
+        project.board.columns[0].tasks.push(task);
+        project.board.columns[3]
+            .tasks
+            .push(Task::new("Ship it".to_string()));
+
+        let markdown = export_board_markdown(&project);
+
+        assert!(markdown.starts_with("# Work\n"));
+        assert!(
+            markdown.contains("## To Do\n\n- [ ] Fix parser `#bug`\n  First line\n  Second line\n")
+        );
+        assert!(markdown.contains("## Done\n\n- [x] Ship it\n"));
+    }
 }
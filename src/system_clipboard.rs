@@ -0,0 +1,21 @@
+use std::process::Command;
+
+// clipboard readers to try in order: wayland, x11 (two tools), macos
+const PASTE_COMMANDS: &[(&str, &[&str])] = &[
+    ("wl-paste", &["--no-newline"]),
+    ("xclip", &["-selection", "clipboard", "-out"]),
+    ("xsel", &["--clipboard", "--output"]),
+    ("pbpaste", &[]),
+];
+
+// read text from the system clipboard through the first tool that works
+pub fn read_text() -> Result<String, String> {
+    for (program, args) in PASTE_COMMANDS {
+        if let Ok(output) = Command::new(program).args(*args).output() {
+            if output.status.success() {
+                return Ok(String::from_utf8_lossy(&output.stdout).into_owned());
+            }
+        }
+    }
+    Err("no clipboard available (install wl-clipboard, xclip or xsel)".to_string())
+}
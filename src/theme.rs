@@ -0,0 +1,60 @@
+use ratatui::style::Color;
+
+// colors used across the UI
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Theme {
+    pub name: &'static str,
+    pub accent: Color,      // selected borders, headings
+    pub highlight: Color,   // focused fields, prompts, project name
+    pub text: Color,        // card titles, list entries
+    pub muted: Color,       // hints and secondary text
+    pub card_border: Color, // unselected card borders
+    pub selected_bg: Color, // selected card / input background
+    pub success: Color,     // current project, status messages
+    pub danger: Color,      // delete prompts and warnings
+}
+
+impl Theme {
+    // default theme for dark terminals
+    pub const DARK: Theme = Theme {
+        name: "dark",
+        accent: Color::Cyan,
+        highlight: Color::Yellow,
+        text: Color::White,
+        muted: Color::DarkGray,
+        card_border: Color::Gray,
+        selected_bg: Color::DarkGray,
+        success: Color::Green,
+        danger: Color::Red,
+    };
+
+    // theme for light terminals
+    pub const LIGHT: Theme = Theme {
+        name: "light",
+        accent: Color::Blue,
+        highlight: Color::Magenta,
+        text: Color::Black,
+        muted: Color::Gray,
+        card_border: Color::DarkGray,
+        selected_bg: Color::Rgb(215, 215, 215),
+        success: Color::Green,
+        danger: Color::Red,
+    };
+
+    // look up a theme by name, falling back to dark
+    pub fn by_name(name: &str) -> Theme {
+        match name {
+            "light" => Theme::LIGHT,
+            _ => Theme::DARK,
+        }
+    }
+
+    // the other preset
+    pub fn toggled(&self) -> Theme {
+        if self.name == Theme::LIGHT.name {
+            Theme::DARK
+        } else {
+            Theme::LIGHT
+        }
+    }
+}
@@ -1,33 +1,114 @@
-use crate::app::{App, InputMode};
-use crate::board::{BoardColumn, Task}; // Removed Board as it's not directly used here
+use crate::app::{App, DeleteTarget, InputMode};
+use crate::board::{template_columns, BoardColumn, Task, COLUMN_TEMPLATES, PREDEFINED_TAGS}; // Removed Board as it's not directly used here
+use crate::datetime;
+use crate::keymap;
+use crate::markdown;
+use crate::theme::Theme;
 use ratatui::{
-    layout::{Constraint, Direction, Layout, Rect},
+    buffer::Buffer,
+    layout::{Constraint, Direction, Layout, Margin, Rect},
     style::{Color, Modifier, Style},
-    text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, Wrap},
+    text::{Line, Span, Text},
+    widgets::{
+        Block, BorderType, Borders, Clear, Gauge, Paragraph, Scrollbar, ScrollbarOrientation,
+        ScrollbarState, Wrap,
+    },
     Frame,
 };
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 // Define a static empty vector for tasks to avoid temporary value errors
 static EMPTY_TASK_VEC: Vec<Task> = Vec::new();
 
-// render UI
+// render UI, without any colors or text attributes in no-color mode
 pub fn draw(f: &mut Frame, app: &mut App) {
+    draw_view(f, app);
+    if app.no_color {
+        strip_styles(f.buffer_mut());
+    }
+}
+
+// reset every cell to the terminal's default style, so no fg/bg/modifier survives
+fn strip_styles(buf: &mut Buffer) {
+    for cell in buf.content.iter_mut() {
+        cell.set_style(Style::reset());
+    }
+}
+
+// border style picked with `border_type` in settings.json
+fn border_type(app: &App) -> BorderType {
+    match app.settings.border_type.as_str() {
+        "rounded" => BorderType::Rounded,
+        "double" => BorderType::Double,
+        "thick" => BorderType::Thick,
+        _ => BorderType::Plain,
+    }
+}
+
+// every bordered box goes through here so the configured border type applies everywhere
+fn bordered(app: &App) -> Block<'static> {
+    Block::default()
+        .borders(Borders::ALL)
+        .border_type(border_type(app))
+}
+
+// selection can't be shown by color in no-color mode, use a double border instead
+// (or a thick one if double is already the configured style)
+fn focus_border(app: &App, is_focused: bool) -> BorderType {
+    match (is_focused && app.no_color, border_type(app)) {
+        (true, BorderType::Double) => BorderType::Thick,
+        (true, _) => BorderType::Double,
+        (false, configured) => configured,
+    }
+}
+
+// terminals shorter than this give the key legend's row to the view
+const MIN_HEIGHT_FOR_HINTS: u16 = 16;
+
+fn draw_view(f: &mut Frame, app: &mut App) {
     // check if we're in a special view mode
-    match app.input_mode {
-        InputMode::ViewingTask | InputMode::EditingTitle | InputMode::EditingDescription => {
-            draw_task_detail(f, app);
-            return;
-        }
-        InputMode::ViewingHelp => {
-            draw_help(f, app);
-            return;
+    let view: Option<fn(&mut Frame, &mut App, Rect)> = match app.input_mode {
+        InputMode::EditingTitle if app.renaming_inline => None,
+        InputMode::ViewingTask
+        | InputMode::EditingTitle
+        | InputMode::EditingDescription
+        | InputMode::EditingTag
+        | InputMode::EditingEstimate
+        | InputMode::EditingDueDate
+        | InputMode::SearchingDescription => Some(draw_task_detail),
+        InputMode::ViewingHelp => Some(draw_help),
+        InputMode::ConfirmDelete
+            if matches!(app.pending_delete, Some(DeleteTarget::Project { .. })) =>
+        {
+            Some(draw_project_list)
         }
-        InputMode::ProjectList | InputMode::AddingProject => {
-            draw_project_list(f, app);
+        InputMode::ProjectList
+        | InputMode::AddingProject
+        | InputMode::ColoringProject
+        | InputMode::PickingTemplate
+        | InputMode::MovingTaskToProject
+        | InputMode::MergingProject => Some(draw_project_list),
+        InputMode::ViewingArchive => Some(draw_archive),
+        InputMode::ActivityLog => Some(draw_activity_log),
+        InputMode::TagLegend => Some(draw_tag_legend),
+        InputMode::Upcoming => Some(draw_upcoming),
+        InputMode::Minimap => Some(draw_minimap),
+        InputMode::PickingBlocker => Some(draw_blocker_picker),
+        _ => None,
+    };
+
+    // full screen views keep the last row for the key legend, unless space is tight
+    if let Some(view) = view {
+        let area = f.area();
+        if area.height < MIN_HEIGHT_FOR_HINTS {
+            view(f, app, area);
             return;
         }
-        _ => {}
+        let [view_area, hints_area] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(area);
+        view(f, app, view_area);
+        f.render_widget(Paragraph::new(key_hints(app)), hints_area);
+        return;
     }
 
     // make three workspaces: header, main area, and footer
@@ -48,30 +129,150 @@ pub fn draw(f: &mut Frame, app: &mut App) {
 
     // footer with help text or input field
     draw_footer(f, app, chunks[2]);
+
+    if app.input_mode == InputMode::AddingTag {
+        draw_tag_picker(f, app, chunks[1]);
+    }
+}
+
+// list of predefined tags floating above the footer while adding a tag
+fn draw_tag_picker(f: &mut Frame, app: &App, area: Rect) {
+    let theme = app.theme;
+    let suggestions = app.tag_suggestions();
+    if suggestions.is_empty() {
+        return;
+    }
+
+    let height = (suggestions.len() as u16 + 2).min(area.height);
+    let width = 32.min(area.width);
+    let picker_area = Rect {
+        x: area.x + 1,
+        y: area.y + area.height - height,
+        width,
+        height,
+    };
+
+    let lines: Vec<Line> = suggestions
+        .iter()
+        .enumerate()
+        .map(|(i, tag)| {
+            let style = Style::default().fg(Task::get_tag_color(tag));
+            if app.selected_suggestion == Some(i) {
+                Line::from(Span::styled(
+                    format!("> #{}", tag),
+                    style.bg(theme.selected_bg).add_modifier(Modifier::BOLD),
+                ))
+            } else {
+                Line::from(Span::styled(format!("  #{}", tag), style))
+            }
+        })
+        .collect();
+
+    f.render_widget(Clear, picker_area);
+    f.render_widget(
+        Paragraph::new(lines).block(
+            bordered(app)
+                .border_style(Style::default().fg(theme.highlight))
+                .title(" Tags (↑/↓ pick, Enter apply) "),
+        ),
+        picker_area,
+    );
 }
 
 // draw header with f and app (immutable)
 fn draw_header(f: &mut Frame, app: &App, area: Rect) {
+    let theme = app.theme;
     let project_name = app.project_name();
-    let header_text = vec![Line::from(vec![
+    let project_accent = app
+        .projects
+        .get(app.current_project)
+        .and_then(|p| p.accent());
+    let mut header_text = vec![Line::from(vec![
         Span::styled(
             "Project: ",
             Style::default()
-                .fg(Color::Cyan)
+                .fg(theme.accent)
                 .add_modifier(Modifier::BOLD),
         ),
         Span::styled(
             project_name,
             Style::default()
-                .fg(Color::Yellow)
+                .fg(project_accent.unwrap_or(theme.highlight))
                 .add_modifier(Modifier::BOLD),
         ),
-        Span::styled("  (Ctrl+P to switch)", Style::default().fg(Color::DarkGray)),
+        Span::styled("  (Ctrl+P to switch)", Style::default().fg(theme.muted)),
     ])];
+    if let Some(tag) = &app.active_tag_filter {
+        header_text[0].spans.push(Span::styled(
+            format!("  [filter: #{}]", tag),
+            Style::default()
+                .fg(Task::get_tag_color(tag))
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+    if !app.confirm_delete {
+        header_text[0].spans.push(Span::styled(
+            "  [instant delete]",
+            Style::default().fg(theme.danger),
+        ));
+    }
+    if let Some(cut) = &app.clipboard {
+        header_text[0].spans.push(Span::styled(
+            format!("  [cut: {}]", truncate_to_width(&cut.task.title, 20)),
+            Style::default().fg(theme.highlight),
+        ));
+    }
+    if !app.marked.is_empty() {
+        header_text[0].spans.push(Span::styled(
+            format!("  [{} marked]", app.marked.len()),
+            Style::default().fg(theme.highlight),
+        ));
+    }
+    if let Some(err) = &app.save_error {
+        header_text[0].spans.push(Span::styled(
+            format!("  [NOT SAVED: {}]", err),
+            Style::default()
+                .fg(theme.danger)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+
+    let block = bordered(app);
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    // project info on the left, then the clock and the completion gauge on the right
+    let parts = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Min(0),
+            Constraint::Length(7),
+            Constraint::Length(32),
+        ])
+        .split(inner);
+    f.render_widget(Paragraph::new(header_text), parts[0]);
 
-    let header = Paragraph::new(header_text).block(Block::default().borders(Borders::ALL));
+    // the event loop polls every 250ms, so this stays current without input
+    let clock = Paragraph::new(datetime::format_local_time(datetime::now()))
+        .style(Style::default().fg(theme.muted));
+    f.render_widget(clock, parts[1]);
 
-    f.render_widget(header, area);
+    let (total, done) = app.board().progress();
+    let ratio = if total == 0 {
+        0.0
+    } else {
+        done as f64 / total as f64
+    };
+    let gauge = Gauge::default()
+        .gauge_style(Style::default().fg(theme.success).bg(theme.selected_bg))
+        .ratio(ratio)
+        .label(format!(
+            "{} tasks · {} done ({}%)",
+            total,
+            done,
+            (ratio * 100.0).round() as u32
+        ));
+    f.render_widget(gauge, parts[2]);
 }
 
 // draw the columns dynamically
@@ -82,14 +283,52 @@ fn draw_columns(f: &mut Frame, app: &mut App, area: Rect) {
         let empty_message =
             Paragraph::new("No columns defined. Press Shift+C to add a new column.")
                 .alignment(ratatui::layout::Alignment::Center)
-                .block(Block::default().borders(Borders::ALL).title("Board"));
+                .block(bordered(app).title("Board"));
         f.render_widget(empty_message, area);
         return;
     }
 
-    // Split main area into dynamic number of columns
-    let constraints: Vec<Constraint> = (0..num_columns)
-        .map(|_| Constraint::Percentage(100 / num_columns as u16))
+    // below a readable width per column show a hint instead of slivers
+    let collapsed = app
+        .board()
+        .columns
+        .iter()
+        .filter(|col| col.collapsed)
+        .count();
+    let room = area
+        .width
+        .saturating_sub(collapsed as u16 * COLLAPSED_COLUMN_WIDTH);
+    // width weights of the expanded columns, the narrowest one decides if it still fits
+    let weights: Vec<u16> = app
+        .board()
+        .columns
+        .iter()
+        .filter(|col| !col.collapsed)
+        .map(|col| col.width_weight())
+        .collect();
+    let total_weight: u32 = weights.iter().map(|&w| w as u32).sum();
+    let narrowest = weights.iter().min().map_or(0, |&w| w as u32);
+    if total_weight > 0 && (room as u32 * narrowest / total_weight) < MIN_COLUMN_WIDTH as u32 {
+        let message = Paragraph::new("Terminal too small, widen it or collapse columns (z)")
+            .style(Style::default().fg(app.theme.muted))
+            .alignment(ratatui::layout::Alignment::Center)
+            .wrap(Wrap { trim: true });
+        f.render_widget(message, area);
+        return;
+    }
+
+    // Split main area into dynamic number of columns, collapsed ones get a fixed strip
+    let constraints: Vec<Constraint> = app
+        .board()
+        .columns
+        .iter()
+        .map(|column| {
+            if column.collapsed {
+                Constraint::Length(COLLAPSED_COLUMN_WIDTH)
+            } else {
+                Constraint::Fill(column.width_weight())
+            }
+        })
         .collect();
 
     let columns_layout = Layout::default()
@@ -101,19 +340,123 @@ fn draw_columns(f: &mut Frame, app: &mut App, area: Rect) {
     // This allows draw_column to take an immutable reference to app
     let mut new_visible_items = app.visible_items; // Capture current value
     if let Some(selected_column_layout_area) = columns_layout.get(app.selected_column) {
-        let card_height = 5;
+        let card_height = base_card_height(app);
         let card_spacing = 1;
-        new_visible_items =
-            (selected_column_layout_area.height / (card_height + card_spacing)).max(1) as usize;
+        // an expanded card takes the room of a few normal ones
+        let extra_height = app.selected_task().map_or(0, |task| {
+            card_height_for(
+                app,
+                task,
+                app.expand_selected,
+                description_width(selected_column_layout_area.width),
+            ) - card_height
+        });
+        // leave room for the borders and both "more" markers
+        new_visible_items = (selected_column_layout_area
+            .height
+            .saturating_sub(3)
+            .saturating_sub(extra_height)
+            / (card_height + card_spacing))
+            .max(1) as usize;
+    }
+    // apply it before drawing so the selected card is scrolled into view right away
+    if new_visible_items != app.visible_items {
+        app.visible_items = new_visible_items;
+        app.update_scroll();
     }
 
     // Now iterate and draw, app can be borrowed immutably
     for (i, board_column) in app.board().columns.iter().enumerate() {
         // draw_column now takes an immutable reference to app
-        draw_column(f, app, i, board_column, columns_layout[i]);
+        if board_column.collapsed {
+            draw_collapsed_column(f, app, i, board_column, columns_layout[i]);
+        } else {
+            draw_column(f, app, i, board_column, columns_layout[i]);
+        }
+    }
+}
+
+// card height on the board (5 lines: top border, title, tags, padding, bottom border)
+const CARD_HEIGHT: u16 = 5;
+
+// most description lines an expanded card shows
+const EXPANDED_DESCRIPTION_LINES: u16 = 6;
+
+// text width inside a card of a column this wide (column and card borders, scrollbar)
+fn description_width(column_width: u16) -> u16 {
+    column_width.saturating_sub(5)
+}
+
+// height of every card, one line taller when the show_descriptions setting is on
+fn base_card_height(app: &App) -> u16 {
+    if app.settings.show_descriptions {
+        CARD_HEIGHT + 1
+    } else {
+        CARD_HEIGHT
+    }
+}
+
+// height of a card, taller when it's expanded to show its description
+fn card_height_for(app: &App, task: &Task, expanded: bool, width: u16) -> u16 {
+    let description = task.description.trim();
+    if !expanded || description.is_empty() {
+        return base_card_height(app);
     }
-    // Finally, apply the new visible_items value after all immutable borrows of app are done.
-    app.visible_items = new_visible_items;
+    let lines: u16 = description
+        .split('\n')
+        .map(|line| wrapped_line_count(line, width))
+        .sum();
+    base_card_height(app).max(CARD_HEIGHT + lines.min(EXPANDED_DESCRIPTION_LINES))
+}
+
+// width of a collapsed column strip (borders plus a few chars)
+const COLLAPSED_COLUMN_WIDTH: u16 = 5;
+
+// narrowest a column gets before the board asks for a bigger terminal
+const MIN_COLUMN_WIDTH: u16 = 12;
+
+/// draw a collapsed column as a narrow strip with its name running down and the task count
+fn draw_collapsed_column(
+    f: &mut Frame,
+    app: &App,
+    column_idx: usize,
+    board_column: &BoardColumn,
+    area: Rect,
+) {
+    let theme = app.theme;
+    let column_color = board_column.accent();
+    let border_style = if app.selected_column == column_idx {
+        Style::default()
+            .fg(column_color.unwrap_or(theme.accent))
+            .add_modifier(Modifier::BOLD)
+    } else {
+        column_color.map_or(Style::default(), |color| Style::default().fg(color))
+    };
+    let block = bordered(app)
+        .border_type(focus_border(app, app.selected_column == column_idx))
+        .border_style(border_style);
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    // count on top, then one char of the name per line
+    let mut lines = vec![
+        Line::from(Span::styled(
+            board_column.tasks.len().to_string(),
+            Style::default().fg(theme.muted),
+        )),
+        Line::from(""),
+    ];
+    lines.extend(
+        board_column
+            .name
+            .chars()
+            .take((inner.height as usize).saturating_sub(2))
+            .map(|c| Line::from(c.to_string())),
+    );
+    f.render_widget(
+        Paragraph::new(lines).alignment(ratatui::layout::Alignment::Center),
+        inner,
+    );
 }
 
 /// draw single column with task cards
@@ -124,27 +467,52 @@ fn draw_column(
     board_column: &BoardColumn,
     area: Rect,
 ) {
+    let theme = app.theme;
     let is_selected_column = app.selected_column == column_idx;
+    let column_color = board_column.accent();
 
-    // highlight border if selected column
+    // highlight border if selected column, tinted with the column color when set
     let border_style = if is_selected_column {
         Style::default()
-            .fg(Color::Cyan)
+            .fg(column_color.unwrap_or(theme.accent))
             .add_modifier(Modifier::BOLD)
+    } else {
+        column_color.map_or(Style::default(), |color| Style::default().fg(color))
+    };
+
+    // column title with live task count, count dimmed when the column is empty
+    let task_count = board_column.tasks.len();
+    let count_style = if task_count == 0 {
+        Style::default().fg(theme.muted).add_modifier(Modifier::DIM)
     } else {
         Style::default()
     };
+    let mut title = Line::from(vec![
+        Span::raw(format!("{} ", board_column.name)),
+        Span::styled(format!("({})", task_count), count_style),
+    ]);
+    if board_column.backlog {
+        title
+            .spans
+            .push(Span::styled(" · backlog", Style::default().fg(theme.muted)));
+    }
+    let total_estimate = board_column.total_estimate();
+    if total_estimate > 0.0 {
+        title.spans.push(Span::styled(
+            format!(" · {}", crate::board::format_estimate(total_estimate)),
+            Style::default().fg(theme.muted),
+        ));
+    }
 
-    let outer_block = Block::default()
-        .borders(Borders::ALL)
+    let outer_block = bordered(app)
+        .border_type(focus_border(app, is_selected_column))
         .border_style(border_style)
-        .title(board_column.name.as_str()); // Use board_column.name
+        .title(title);
 
-    let inner_area = outer_block.inner(area);
+    let mut inner_area = outer_block.inner(area);
     f.render_widget(outer_block, area);
 
-    // calculate card height (5 lines: top border, title, tags, padding, bottom border)
-    let card_height = 5;
+    let card_height = base_card_height(app);
     let card_spacing = 1; // space between cards
 
     // visible items is now set outside this function in draw_columns
@@ -156,16 +524,113 @@ fn draw_column(
         0
     };
 
-    // now get the tasks
+    // now get the tasks that pass the tag filter
     let tasks = &board_column.tasks;
+    let visible = app.visible_task_indices(column_idx);
+
+    // card heights from the scroll offset on, the selected one may be expanded
+    let heights: Vec<u16> = visible
+        .iter()
+        .skip(scroll_offset)
+        .map(|&i| {
+            let expanded = app.expand_selected && is_selected_column && i == app.selected_index;
+            card_height_for(app, &tasks[i], expanded, description_width(area.width))
+        })
+        .collect();
+
+    // how many cards fit in the given height (a tall first card is cut short)
+    let fit_in = |height: u16| {
+        let mut used = 0;
+        let mut fit = 0;
+        for &card in &heights {
+            let card = if fit == 0 { card.min(height) } else { card };
+            if card < card_height || used + card > height {
+                break;
+            }
+            used += card + card_spacing;
+            fit += 1;
+        }
+        fit
+    };
+    let mut cards_fit = fit_in(inner_area.height);
+
+    // "more" markers above/below the cards take a row each when there are hidden cards
+    let muted = Style::default().fg(theme.muted);
+    if scroll_offset > 0 && inner_area.height > 1 {
+        let marker_area = Rect {
+            height: 1,
+            ..inner_area
+        };
+        f.render_widget(
+            Paragraph::new(Span::styled(format!("▲ {} more", scroll_offset), muted))
+                .alignment(ratatui::layout::Alignment::Center),
+            marker_area,
+        );
+        inner_area.y += 1;
+        inner_area.height -= 1;
+        cards_fit = fit_in(inner_area.height);
+    }
+    if visible.len() > scroll_offset + cards_fit && inner_area.height > 1 {
+        inner_area.height -= 1;
+        cards_fit = fit_in(inner_area.height);
+        let hidden_below =
+            visible.len() - scroll_offset - cards_fit.min(visible.len() - scroll_offset);
+        let marker_area = Rect {
+            y: inner_area.y + inner_area.height,
+            height: 1,
+            ..inner_area
+        };
+        f.render_widget(
+            Paragraph::new(Span::styled(format!("▼ {} more", hidden_below), muted))
+                .alignment(ratatui::layout::Alignment::Center),
+            marker_area,
+        );
+    }
+
+    // scrollbar on the selected column when cards overflow, reserving its track width
+    if is_selected_column && visible.len() > cards_fit && inner_area.width > 1 {
+        let mut scrollbar_state = ScrollbarState::new(visible.len())
+            .viewport_content_length(cards_fit)
+            .position(scroll_offset);
+        f.render_stateful_widget(
+            Scrollbar::new(ScrollbarOrientation::VerticalRight),
+            inner_area,
+            &mut scrollbar_state,
+        );
+        inner_area.width -= 1;
+    }
+
+    // hint in empty columns (or when the tag filter hides everything)
+    if visible.is_empty() && inner_area.height > 0 {
+        let hint = if tasks.is_empty() {
+            "No tasks — press 'a' to add"
+        } else {
+            "No tasks match the filter"
+        };
+        let hint_area = Rect {
+            y: inner_area.y + inner_area.height / 2,
+            height: inner_area.height - inner_area.height / 2,
+            ..inner_area
+        };
+        f.render_widget(
+            Paragraph::new(Span::styled(hint, muted.add_modifier(Modifier::DIM)))
+                .alignment(ratatui::layout::Alignment::Center)
+                .wrap(Wrap { trim: true }),
+            hint_area,
+        );
+    }
 
     // render each task as a card, starting from scroll_offset
-    let mut rendered = 0;
-    for (i, task) in tasks.iter().enumerate().skip(scroll_offset) {
-        let y_offset = rendered as u16 * (card_height + card_spacing);
+    let mut y_offset = 0;
+    for (rendered, &i) in visible.iter().skip(scroll_offset).enumerate() {
+        let task = &tasks[i];
+        let mut height = heights[rendered];
+        if rendered == 0 {
+            height = height.min(inner_area.height);
+        }
 
         // stop if we run out of space
-        if y_offset + card_height > inner_area.height {
+        if height < card_height || y_offset + height > inner_area.height {
             break;
         }
 
@@ -173,148 +638,537 @@ fn draw_column(
             x: inner_area.x,
             y: inner_area.y + y_offset,
             width: inner_area.width,
-            height: card_height,
+            height,
         };
+        y_offset += height + card_spacing;
 
         draw_task_card(
             f,
+            app,
             task,
             card_area,
             is_selected_column && i == app.selected_index,
+            task.accent().or(column_color),
         );
-        rendered += 1;
     }
 }
 
+// every date on screen goes through these, using `date_format` from settings.json
+fn format_date(app: &App, date: &str) -> String {
+    datetime::format_date(date, &app.settings.date_format)
+}
+
+// dates on cards leave out the year to save space
+fn format_short_date(app: &App, date: &str) -> String {
+    datetime::format_date(date, &datetime::short_format(&app.settings.date_format))
+}
+
+fn format_timestamp(app: &App, ts: Option<datetime::Timestamp>) -> String {
+    ts.map_or("unknown".to_string(), |ts| {
+        datetime::format_local(ts, &app.settings.date_format)
+    })
+}
+
+// overdue dates stand out, the rest are muted
+fn due_style(theme: &Theme, date: &str) -> Style {
+    if date < datetime::today_local().as_str() {
+        Style::default().fg(theme.danger)
+    } else {
+        Style::default().fg(theme.muted)
+    }
+}
+
+// how many tags fit on a card line of this width, and how many go into a "+N" badge
+// (a lone tag that doesn't fit is still shown, truncated)
+fn fit_tags(tags: &[String], width: usize) -> (usize, usize) {
+    let mut remaining = width;
+    for (i, tag) in tags.iter().enumerate() {
+        let left_after = tags.len() - i - 1;
+        let badge = if left_after > 0 {
+            format!("+{}", left_after).len()
+        } else {
+            0
+        };
+        let tag_width = format!("#{} ", tag).width();
+        if tag_width + badge > remaining {
+            if tags.len() == 1 {
+                return (1, 0);
+            }
+            return (i, tags.len() - i);
+        }
+        remaining -= tag_width;
+    }
+    (tags.len(), 0)
+}
+
+// most lines the title box in the detail view grows to
+const MAX_TITLE_LINES: u16 = 5;
+
+// number of lines text takes when word wrapped to width (roughly what Wrap does)
+fn wrapped_line_count(text: &str, width: u16) -> u16 {
+    let width = width.max(1) as usize;
+    let mut lines = 1;
+    let mut line_width = 0;
+    for word in text.split(' ') {
+        let word_width = word.width();
+        if line_width > 0 && line_width + 1 + word_width > width {
+            lines += 1;
+            line_width = 0;
+        }
+        if line_width > 0 {
+            line_width += 1;
+        }
+        // words longer than a line are broken up
+        line_width += word_width;
+        while line_width > width {
+            lines += 1;
+            line_width -= width;
+        }
+    }
+    lines
+}
+
+// split the input buffer into lines, highlighting the char under the cursor
+fn lines_with_caret(app: &App) -> Vec<Line<'_>> {
+    let (cursor_row, cursor_col) = app.cursor_position();
+    let caret = Style::default().add_modifier(Modifier::REVERSED);
+    app.input_buffer
+        .split('\n')
+        .enumerate()
+        .map(|(row, line)| {
+            if row != cursor_row {
+                return Line::from(line);
+            }
+            let split = line
+                .char_indices()
+                .nth(cursor_col)
+                .map_or(line.len(), |(i, _)| i);
+            let (before, rest) = line.split_at(split);
+            let mut chars = rest.chars();
+            let under = chars.next().map_or(" ".to_string(), |c| c.to_string());
+            Line::from(vec![
+                Span::raw(before),
+                Span::styled(under, caret),
+                Span::raw(chars.as_str()),
+            ])
+        })
+        .collect()
+}
+
+// cut text to at most max_width terminal cells, ending in an ellipsis when clipped
+fn truncate_to_width(text: &str, max_width: usize) -> String {
+    if text.width() <= max_width {
+        return text.to_string();
+    }
+    if max_width == 0 {
+        return String::new();
+    }
+
+    let mut out = String::new();
+    let mut width = 0;
+    for c in text.chars() {
+        let char_width = c.width().unwrap_or(0);
+        if width + char_width > max_width - 1 {
+            break;
+        }
+        out.push(c);
+        width += char_width;
+    }
+    out.push('…');
+    out
+}
+
 /// draw a single task card
-fn draw_task_card(f: &mut Frame, task: &Task, area: Rect, is_selected: bool) {
-    // Changed crate::board::Task to Task
-    // card border style
+fn draw_task_card(
+    f: &mut Frame,
+    app: &App,
+    task: &Task,
+    area: Rect,
+    is_selected: bool,
+    accent: Option<Color>,
+) {
+    let theme = app.theme;
+    let is_blocked = app.board().is_blocked(task);
+    let is_marked = app.marked.contains(&task.id);
+    // card border style, the card or column color replaces the theme defaults when set
     let border_style = if is_selected {
         Style::default()
-            .fg(Color::Cyan)
+            .fg(accent.unwrap_or(theme.accent))
             .add_modifier(Modifier::BOLD)
     } else {
-        Style::default().fg(Color::Gray)
+        Style::default().fg(accent.unwrap_or(theme.card_border))
     };
 
     // background color for selected task
     let bg_color = if is_selected {
-        Color::DarkGray
+        theme.selected_bg
     } else {
         Color::Reset
     };
 
-    let card_block = Block::default()
-        .borders(Borders::ALL)
+    let mut card_block = bordered(app)
+        .border_type(focus_border(app, is_selected))
         .border_style(border_style)
         .style(Style::default().bg(bg_color));
 
+    // last change in the bottom border, skipped for migrated tasks without timestamps
+    if let Some(updated_at) = task.updated_at {
+        let age = datetime::humanize_duration(datetime::now() - updated_at);
+        if (area.width as usize) >= age.width() + 4 {
+            card_block = card_block.title_bottom(
+                Line::from(Span::styled(
+                    format!(" {} ", age),
+                    Style::default().fg(theme.muted).add_modifier(Modifier::DIM),
+                ))
+                .right_aligned(),
+            );
+        }
+    }
+
     let inner = card_block.inner(area);
     f.render_widget(card_block, inner); // Changed from card_block.inner(area) to area as inner is already calculated
 
     // render task title and tags on separate lines
     if inner.height >= 2 {
-        // truncate title to fit width
-        let max_title_len = inner.width as usize;
-        let truncated_title: String = task.title.chars().take(max_title_len).collect();
+        // mark and blocked markers go in front of the title
+        let mark_marker = if is_marked { "● " } else { "" };
+        let blocked_marker = if is_blocked { "[blocked] " } else { "" };
+
+        // cards with a description get a marker in the top right corner
+        let has_description = !task.description.trim().is_empty();
+        let marker_width = if has_description { 2 } else { 0 };
+
+        // truncate title to fit width (by display width, so wide chars don't overflow)
+        let max_title_width = (inner.width as usize)
+            .saturating_sub(mark_marker.width())
+            .saturating_sub(blocked_marker.len())
+            .saturating_sub(marker_width);
+        let truncated_title = truncate_to_width(&task.title, max_title_width);
 
         let mut lines = vec![
             // Line 1: Title
-            Line::from(Span::styled(
-                truncated_title,
-                Style::default()
-                    .fg(Color::White)
-                    .add_modifier(if is_selected {
-                        Modifier::BOLD
-                    } else {
-                        Modifier::empty()
-                    }),
-            )),
+            Line::from(vec![
+                Span::styled(
+                    mark_marker,
+                    Style::default()
+                        .fg(theme.highlight)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    blocked_marker,
+                    Style::default()
+                        .fg(theme.danger)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    truncated_title,
+                    Style::default()
+                        .fg(theme.text)
+                        .add_modifier(if is_selected {
+                            Modifier::BOLD
+                        } else {
+                            Modifier::empty()
+                        }),
+                ),
+            ]),
         ];
 
-        // Line 2: Tags (if any) - each tag with its own color
-        if !task.tags.is_empty() {
+        // Line 2: estimate, due date and tags (if any) - each tag with its own color, clipped to the card width
+        if !task.tags.is_empty() || task.estimate.is_some() || task.due_date.is_some() {
             let mut tag_spans = vec![];
-            for tag in &task.tags {
+            let mut remaining = inner.width as usize;
+            if let Some(hours) = task.estimate {
+                let text = truncate_to_width(
+                    &format!("{} ", crate::board::format_estimate(hours)),
+                    remaining,
+                );
+                remaining = remaining.saturating_sub(text.width());
+                tag_spans.push(Span::styled(text, Style::default().fg(theme.muted)));
+            }
+            if let Some(date) = &task.due_date {
+                let text =
+                    truncate_to_width(&format!("due {} ", format_short_date(app, date)), remaining);
+                remaining = remaining.saturating_sub(text.width());
+                tag_spans.push(Span::styled(text, due_style(&theme, date)));
+            }
+            let (shown, overflow) = fit_tags(&task.tags, remaining);
+            for tag in &task.tags[..shown] {
+                let text = truncate_to_width(&format!("#{} ", tag), remaining);
+                remaining = remaining.saturating_sub(text.width());
                 tag_spans.push(Span::styled(
-                    format!("#{} ", tag),
+                    text,
                     Style::default()
                         .fg(crate::board::Task::get_tag_color(tag))
                         .add_modifier(Modifier::DIM),
                 ));
             }
+            if overflow > 0 {
+                tag_spans.push(Span::styled(
+                    truncate_to_width(&format!("+{}", overflow), remaining),
+                    Style::default().fg(theme.muted),
+                ));
+            }
             lines.push(Line::from(tag_spans));
         }
 
         let content = Paragraph::new(lines);
         f.render_widget(content, inner);
+
+        // taller cards (expanded, or all of them with show_descriptions) fill the rows
+        // below the tags with the description
+        if has_description && inner.height > CARD_HEIGHT - 2 {
+            let description_area = Rect {
+                y: inner.y + 2,
+                height: inner.height - 3,
+                ..inner
+            };
+            f.render_widget(
+                Paragraph::new(task.description.trim())
+                    .style(Style::default().fg(theme.muted))
+                    .wrap(Wrap { trim: false }),
+                description_area,
+            );
+        }
+
+        if has_description && inner.width > 0 {
+            let corner = Rect::new(inner.x + inner.width - 1, inner.y, 1, 1);
+            f.render_widget(
+                Paragraph::new(Span::styled("≡", Style::default().fg(theme.muted))),
+                corner,
+            );
+        }
+    }
+}
+
+// "keys: action" legend for the current mode, normal mode keys follow keybindings.json
+fn key_hints(app: &App) -> Line<'static> {
+    let theme = app.theme;
+    let mut spans = Vec::new();
+    for hint in keymap::hints_for(&app.input_mode) {
+        let Some(keys) = app.keymap.hint_keys(hint) else {
+            continue;
+        };
+        if !spans.is_empty() {
+            spans.push(Span::styled(" | ", Style::default().fg(theme.muted)));
+        }
+        spans.push(Span::styled(
+            keys,
+            Style::default()
+                .fg(theme.highlight)
+                .add_modifier(Modifier::BOLD),
+        ));
+        spans.push(Span::raw(format!(": {}", hint.text)));
     }
+    Line::from(spans)
 }
 
 // draw footer with help text or input field
 fn draw_footer(f: &mut Frame, app: &mut App, area: Rect) {
+    let theme = app.theme;
     let text = match app.input_mode {
-        InputMode::Normal => {
+        InputMode::Normal if app.flash_text().is_some() => {
+            vec![Line::from(Span::styled(
+                app.flash_text().unwrap_or_default().to_string(),
+                Style::default().fg(theme.success),
+            ))]
+        }
+        InputMode::Normal if app.pending_count.is_some() => {
             vec![Line::from(vec![
-                Span::raw("hjkl/arrows: navigate | "),
-                Span::raw("Enter: open task | "),
-                Span::raw("a: add task | "),
-                Span::raw("t: add tag | "),
-                Span::raw("m: move task forward | "),
-                Span::raw("n: move task back | "),
-                Span::raw("d: delete task | "),
-                Span::raw("?: help | "),
-                Span::raw("q: quit"),
+                Span::styled(
+                    format!("{}", app.pending_count.unwrap_or_default()),
+                    Style::default()
+                        .fg(theme.highlight)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    "  (count for the next move, Esc to clear)",
+                    Style::default().fg(theme.muted),
+                ),
             ])]
         }
+        InputMode::Normal => vec![key_hints(app)],
         InputMode::AddingTask => {
+            let prompt = if app.sticky_add {
+                "Add Tasks (Esc when done): "
+            } else {
+                "Add Task: "
+            };
             vec![
                 Line::from(vec![
-                    Span::styled("Add Task: ", Style::default().fg(Color::Yellow)),
+                    Span::styled(prompt, Style::default().fg(theme.highlight)),
                     Span::raw(&app.input_buffer),
                 ]),
-                Line::from("Press Enter to submit, Esc to cancel"),
+                key_hints(app),
             ]
         }
         InputMode::AddingTag => {
             vec![
                 Line::from(vec![
-                    Span::styled("Add Tag: ", Style::default().fg(Color::Yellow)),
+                    Span::styled("Add Tag: ", Style::default().fg(theme.highlight)),
                     Span::raw(&app.input_buffer),
                 ]),
-                Line::from("Press Enter to submit, Esc to cancel"),
+                key_hints(app),
             ]
         }
         InputMode::AddingColumn => {
             vec![
                 Line::from(vec![
-                    Span::styled("Add Column: ", Style::default().fg(Color::Yellow)),
+                    Span::styled("Add Column: ", Style::default().fg(theme.highlight)),
+                    Span::raw(&app.input_buffer),
+                ]),
+                key_hints(app),
+            ]
+        }
+        InputMode::FilteringTag => {
+            vec![
+                Line::from(vec![
+                    Span::styled("Filter by Tag: ", Style::default().fg(theme.highlight)),
                     Span::raw(&app.input_buffer),
                 ]),
-                Line::from("Press Enter to submit, Esc to cancel"),
+                key_hints(app),
             ]
         }
         InputMode::RenamingColumn => {
             vec![
                 Line::from(vec![
-                    Span::styled("Rename Column: ", Style::default().fg(Color::Yellow)),
+                    Span::styled("Rename Column: ", Style::default().fg(theme.highlight)),
+                    Span::raw(&app.input_buffer),
+                ]),
+                key_hints(app),
+            ]
+        }
+        InputMode::EditingTitle => {
+            vec![
+                Line::from(vec![
+                    Span::styled("Rename Task: ", Style::default().fg(theme.highlight)),
+                    Span::raw(&app.input_buffer),
+                ]),
+                key_hints(app),
+            ]
+        }
+        InputMode::Command => {
+            vec![
+                Line::from(vec![
+                    Span::styled(":", Style::default().fg(theme.highlight)),
+                    Span::raw(&app.input_buffer),
+                ]),
+                Line::from(
+                    "add <title> | tag <name> | column add/rename/delete | project <name> | filter [tag] | export md | status | quit",
+                ),
+            ]
+        }
+        InputMode::RenamingTagEverywhere => {
+            let prompt = match &app.tag_rename_from {
+                None => "Rename tag everywhere: ".to_string(),
+                Some(old) => format!("Rename #{} to: ", old),
+            };
+            vec![
+                Line::from(vec![
+                    Span::styled(prompt, Style::default().fg(theme.highlight)),
+                    Span::raw(&app.input_buffer),
+                ]),
+                key_hints(app),
+            ]
+        }
+        InputMode::TaggingColumn | InputMode::UntaggingColumn => {
+            let prompt = if app.input_mode == InputMode::TaggingColumn {
+                "Tag every card in column: "
+            } else {
+                "Remove tag from every card in column: "
+            };
+            vec![
+                Line::from(vec![
+                    Span::styled(prompt, Style::default().fg(theme.highlight)),
+                    Span::raw(&app.input_buffer),
+                ]),
+                key_hints(app),
+            ]
+        }
+        InputMode::ColoringColumn => {
+            vec![
+                Line::from(vec![
+                    Span::styled(
+                        "Column Color (name or #hex, empty to reset): ",
+                        Style::default().fg(theme.highlight),
+                    ),
                     Span::raw(&app.input_buffer),
                 ]),
-                Line::from("Press Enter to submit, Esc to cancel"),
+                // an unknown color keeps the prompt open, say why in place of the hints
+                match app.flash_text() {
+                    Some(err) => Line::from(Span::styled(
+                        err.to_string(),
+                        Style::default().fg(theme.danger),
+                    )),
+                    None => key_hints(app),
+                },
+            ]
+        }
+        InputMode::ConfirmDelete => {
+            vec![
+                Line::from(Span::styled(
+                    app.delete_prompt().unwrap_or_default(),
+                    Style::default()
+                        .fg(theme.danger)
+                        .add_modifier(Modifier::BOLD),
+                )),
+                key_hints(app),
             ]
         }
-        _ => vec![Line::from("")],
+        _ => vec![key_hints(app)],
     };
 
-    let paragraph = Paragraph::new(text).block(Block::default().borders(Borders::ALL));
+    // color the border by mode so it's obvious what typing will do, normal mode stays neutral
+    let mut block = bordered(app);
+    if let Some((color, label)) = mode_style(&theme, &app.input_mode) {
+        block = block
+            .border_style(Style::default().fg(color))
+            .title(Span::styled(
+                format!(" {} ", label),
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            ));
+    }
+    let paragraph = Paragraph::new(text).block(block);
 
     f.render_widget(paragraph, area);
 }
 
+// border color and label for modes that change what keys do: green while adding,
+// yellow while editing, red while confirming a delete (None for normal and views)
+fn mode_style(theme: &Theme, mode: &InputMode) -> Option<(Color, &'static str)> {
+    match mode {
+        InputMode::AddingTask
+        | InputMode::AddingTag
+        | InputMode::AddingColumn
+        | InputMode::AddingProject
+        | InputMode::PickingTemplate
+        | InputMode::TaggingColumn => Some((theme.success, "ADDING")),
+        InputMode::EditingTitle
+        | InputMode::EditingDescription
+        | InputMode::EditingTag
+        | InputMode::EditingEstimate
+        | InputMode::EditingDueDate
+        | InputMode::RenamingColumn
+        | InputMode::ColoringColumn
+        | InputMode::ColoringProject
+        | InputMode::RenamingTagEverywhere
+        | InputMode::UntaggingColumn
+        | InputMode::FilteringTag
+        | InputMode::SearchingDescription
+        | InputMode::Command => Some((theme.highlight, "EDITING")),
+        InputMode::ConfirmDelete => Some((theme.danger, "DELETE")),
+        _ => None,
+    }
+}
+
+// border of a full screen view, colored like the footer while adding or editing in it
+fn overlay_border(theme: &Theme, mode: &InputMode) -> Style {
+    let color = mode_style(theme, mode).map_or(theme.accent, |(color, _)| color);
+    Style::default().fg(color)
+}
+
 // draw task detail view
-fn draw_task_detail(f: &mut Frame, app: &mut App) {
-    let area = f.area();
+fn draw_task_detail(f: &mut Frame, app: &mut App, area: Rect) {
+    let theme = app.theme;
 
     // get the selected task
     let column_tasks = if let Some(column) = app.board().columns.get(app.selected_column) {
@@ -326,376 +1180,593 @@ fn draw_task_detail(f: &mut Frame, app: &mut App) {
         return;
     }
     let task = &column_tasks[app.selected_index];
+    use crate::app::TaskField;
 
     // check what editing mode we're in
     let is_editing_title = app.input_mode == InputMode::EditingTitle;
     let is_editing_description = app.input_mode == InputMode::EditingDescription;
+    let is_editing_tag = app.input_mode == InputMode::EditingTag;
+    let is_editing_estimate = app.input_mode == InputMode::EditingEstimate;
+    let is_editing_due_date = app.input_mode == InputMode::EditingDueDate;
 
     // create main container with context-aware title
-    let title = if is_editing_title {
-        " Task Details - EDITING TITLE (Enter to save, Esc to cancel) "
+    let title = if is_editing_due_date {
+        " Task Details - EDITING DUE DATE (YYYY-MM-DD) "
+    } else if is_editing_estimate {
+        " Task Details - EDITING ESTIMATE (hours) "
+    } else if is_editing_title {
+        " Task Details - EDITING TITLE "
     } else if is_editing_description {
-        " Task Details - EDITING DESCRIPTION (Enter for newline, Esc to save) "
+        " Task Details - EDITING DESCRIPTION "
+    } else if is_editing_tag {
+        " Task Details - EDITING TAG "
+    } else if app.input_mode == InputMode::SearchingDescription {
+        " Task Details - SEARCH DESCRIPTION "
     } else {
-        " Task Details (Tab: switch field | Enter: edit | 1-9: remove tag | Esc: close) "
+        " Task Details "
     };
 
-    let block = Block::default()
-        .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Cyan))
+    let block = bordered(app)
+        .border_style(overlay_border(&theme, &app.input_mode))
         .title(title);
 
     let inner = block.inner(area);
     f.render_widget(block, area);
 
-    // split into sections
+    // title box grows with the wrapped title (up to a few lines) so long titles aren't cut off
+    let title_body = if is_editing_title {
+        app.input_buffer.clone()
+    } else {
+        format!("Title: {}", task.title)
+    };
+    let title_lines =
+        wrapped_line_count(&title_body, inner.width.saturating_sub(2)).clamp(1, MAX_TITLE_LINES);
+
+    // one row per tag (or "No tags") plus borders, up to nine tags or a third of the view
+    // when that's more, the rest scrolls
+    let tags_height = (task.tags.len().max(1) as u16 + 2).min((9 + 2).max(inner.height / 3));
+
+    // split into sections
     let sections = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(3),  // Title
-            Constraint::Length(12), // Tags (enough for header + up to 9 tags)
-            Constraint::Min(5),     // Description
+            Constraint::Length(title_lines + 2), // Title
+            Constraint::Length(5),               // Timestamps, blockers, color, estimate, due date
+            Constraint::Max(tags_height),        // Tags
+            Constraint::Min(3),                  // Description
         ])
         .split(inner);
 
     // title section - show editable input if editing, otherwise show read-only
-    use crate::app::TaskField;
     let is_title_focused =
         app.focused_field == TaskField::Title && !is_editing_title && !is_editing_description;
 
     if is_editing_title {
         let title_para = Paragraph::new(app.input_buffer.as_str())
             .block(
-                Block::default()
-                    .borders(Borders::ALL)
+                bordered(app)
                     .title("Title [EDITING]")
-                    .border_style(Style::default().fg(Color::Yellow)),
+                    .border_style(Style::default().fg(theme.highlight)),
             )
-            .style(Style::default().bg(Color::DarkGray));
+            .wrap(Wrap { trim: false })
+            .style(Style::default().bg(theme.selected_bg));
         f.render_widget(title_para, sections[0]);
     } else {
         let title_text = vec![Line::from(vec![
             Span::styled(
                 "Title: ",
                 Style::default()
-                    .fg(Color::Cyan)
+                    .fg(theme.accent)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::raw(&task.title),
         ])];
         let border_style = if is_title_focused {
             Style::default()
-                .fg(Color::Yellow)
+                .fg(theme.highlight)
                 .add_modifier(Modifier::BOLD)
         } else {
             Style::default()
         };
-        let title_para = Paragraph::new(title_text).block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_style(border_style),
-        );
+        let title_para = Paragraph::new(title_text)
+            .block(
+                bordered(app)
+                    .border_type(focus_border(app, is_title_focused))
+                    .border_style(border_style),
+            )
+            .wrap(Wrap { trim: false });
         f.render_widget(title_para, sections[0]);
     }
 
+    // timestamps and blockers lines
+    let blockers: Vec<&str> = task
+        .blocked_by
+        .iter()
+        .filter_map(|id| app.board().find_task(id))
+        .map(|(_, blocker)| blocker.title.as_str())
+        .collect();
+    let blockers_line = if blockers.is_empty() {
+        Line::from(Span::styled(
+            " Not blocked (press b to add blockers)",
+            Style::default().fg(theme.muted),
+        ))
+    } else {
+        let style = if app.board().is_blocked(task) {
+            Style::default().fg(theme.danger)
+        } else {
+            Style::default().fg(theme.success)
+        };
+        Line::from(Span::styled(
+            format!(" Blocked by: {}", blockers.join(", ")),
+            style,
+        ))
+    };
+    let color_line = match (&task.color, task.accent()) {
+        (Some(name), Some(color)) => Line::from(vec![
+            Span::styled(" Color: ", Style::default().fg(theme.muted)),
+            Span::styled(format!("■ {}", name), Style::default().fg(color)),
+            Span::styled(" (press c to change)", Style::default().fg(theme.muted)),
+        ]),
+        _ => Line::from(Span::styled(
+            " No card color (press c to pick one)",
+            Style::default().fg(theme.muted),
+        )),
+    };
+    let muted = Style::default().fg(theme.muted);
+    let estimate_line = if is_editing_estimate {
+        let mut spans = vec![
+            Span::styled(" Estimate: ", muted),
+            Span::styled(
+                format!("{}_", app.input_buffer),
+                Style::default().fg(theme.text).bg(theme.selected_bg),
+            ),
+            Span::styled(" hours, empty clears", muted),
+        ];
+        if let Some(err) = app.flash_text() {
+            spans.push(Span::styled(
+                format!("  {}", err),
+                Style::default().fg(theme.danger),
+            ));
+        }
+        Line::from(spans)
+    } else if let Some(hours) = task.estimate {
+        Line::from(vec![
+            Span::styled(" Estimate: ", muted),
+            Span::styled(
+                crate::board::format_estimate(hours),
+                Style::default().fg(theme.text),
+            ),
+            Span::styled(" (press E to change)", muted),
+        ])
+    } else {
+        Line::from(Span::styled(" No estimate (press E to set one)", muted))
+    };
+    let due_line = if is_editing_due_date {
+        let mut spans = vec![
+            Span::styled(" Due: ", muted),
+            Span::styled(
+                format!("{}_", app.input_buffer),
+                Style::default().fg(theme.text).bg(theme.selected_bg),
+            ),
+            Span::styled(" YYYY-MM-DD, empty clears", muted),
+        ];
+        if let Some(err) = app.flash_text() {
+            spans.push(Span::styled(
+                format!("  {}", err),
+                Style::default().fg(theme.danger),
+            ));
+        }
+        Line::from(spans)
+    } else if let Some(date) = &task.due_date {
+        Line::from(vec![
+            Span::styled(" Due: ", muted),
+            Span::styled(format_date(app, date), due_style(&theme, date)),
+            Span::styled(" (press D to change)", muted),
+        ])
+    } else {
+        Line::from(Span::styled(" No due date (press D to set one)", muted))
+    };
+    let times = Paragraph::new(vec![
+        Line::from(Span::styled(
+            format!(
+                " Created: {}  |  Updated: {}",
+                format_timestamp(app, task.created_at),
+                format_timestamp(app, task.updated_at)
+            ),
+            Style::default().fg(theme.muted),
+        )),
+        blockers_line,
+        color_line,
+        estimate_line,
+        due_line,
+    ]);
+    f.render_widget(times, sections[1]);
+
     // tags section - show numbered tags for easy removal
     let is_tags_focused =
         app.focused_field == TaskField::Tags && !is_editing_title && !is_editing_description;
 
+    // the heading is the block title, so it stays put while the tags scroll
+    let tags_title = Line::from(vec![
+        Span::styled(
+            "Tags ",
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            if task.tags.is_empty() {
+                String::new()
+            } else {
+                format!(
+                    "({}, 1-9 or j/k then d/Enter to remove, e to edit)",
+                    task.tags.len()
+                )
+            },
+            Style::default().fg(theme.muted),
+        ),
+    ]);
     let tags_lines = if !task.tags.is_empty() {
-        let mut lines = vec![Line::from(vec![
-            Span::styled(
-                "Tags ",
-                Style::default()
-                    .fg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Span::styled(
-                "(press 1-9 to remove):",
-                Style::default().fg(Color::DarkGray),
-            ),
-        ])];
+        let mut lines = Vec::new();
         for (i, tag) in task.tags.iter().enumerate() {
-            if i < 9 {
-                lines.push(Line::from(vec![
-                    Span::styled(
-                        format!(" {} ", i + 1),
-                        Style::default()
-                            .fg(Color::Yellow)
-                            .add_modifier(Modifier::BOLD),
-                    ),
-                    Span::styled(
-                        format!("#{}", tag),
-                        Style::default().fg(crate::board::Task::get_tag_color(tag)),
-                    ),
-                ]));
-            }
+            let is_selected = (is_tags_focused || is_editing_tag) && i == app.selected_tag_index;
+            let tag_span = if is_editing_tag && is_selected {
+                Span::styled(
+                    format!("#{}_", app.input_buffer),
+                    Style::default().fg(theme.text).bg(theme.selected_bg),
+                )
+            } else {
+                Span::styled(
+                    format!("#{}", tag),
+                    Style::default().fg(crate::board::Task::get_tag_color(tag)),
+                )
+            };
+            // only the first nine have a number key
+            let number = if i < 9 {
+                format!(" {} ", i + 1)
+            } else {
+                "   ".to_string()
+            };
+            let line = Line::from(vec![
+                Span::styled(
+                    number,
+                    Style::default()
+                        .fg(theme.highlight)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                tag_span,
+            ]);
+            lines.push(if is_selected {
+                line.style(Style::default().bg(theme.selected_bg))
+            } else {
+                line
+            });
         }
         lines
     } else {
         vec![Line::from(Span::styled(
             "No tags",
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(theme.muted),
         ))]
     };
     let border_style = if is_tags_focused {
         Style::default()
-            .fg(Color::Yellow)
+            .fg(theme.highlight)
             .add_modifier(Modifier::BOLD)
     } else {
         Style::default()
     };
-    let tags_para = Paragraph::new(tags_lines).block(
-        Block::default()
-            .borders(Borders::ALL)
-            .border_style(border_style),
-    );
-    f.render_widget(tags_para, sections[1]);
+    // scroll so the highlighted tag stays visible
+    let tag_rows = sections[2].height.saturating_sub(2) as usize;
+    let tags_scroll = (app.selected_tag_index + 1).saturating_sub(tag_rows);
+    let tags_para = Paragraph::new(tags_lines)
+        .block(
+            bordered(app)
+                .border_type(focus_border(app, is_tags_focused))
+                .border_style(border_style)
+                .title(tags_title),
+        )
+        .scroll((tags_scroll as u16, 0));
+    f.render_widget(tags_para, sections[2]);
+
+    // scrollbar on the right border when not every tag fits
+    if task.tags.len() > tag_rows && tag_rows > 0 {
+        let mut scrollbar_state = ScrollbarState::new(task.tags.len())
+            .viewport_content_length(tag_rows)
+            .position(tags_scroll);
+        f.render_stateful_widget(
+            Scrollbar::new(ScrollbarOrientation::VerticalRight),
+            sections[2].inner(Margin::new(0, 1)),
+            &mut scrollbar_state,
+        );
+    }
 
     // description section - show input field if editing, otherwise show text
     let is_desc_focused =
         app.focused_field == TaskField::Description && !is_editing_title && !is_editing_description;
 
     if is_editing_description {
-        // Show editable input field
-        let desc_para = Paragraph::new(app.input_buffer.as_str())
+        // Show editable input field with the caret drawn as a reversed cell
+        let desc_para = Paragraph::new(lines_with_caret(app))
             .block(
-                Block::default()
-                    .borders(Borders::ALL)
+                bordered(app)
                     .title("Description [EDITING]")
-                    .border_style(Style::default().fg(Color::Yellow)),
+                    .border_style(Style::default().fg(theme.highlight)),
             )
             .wrap(Wrap { trim: false })
-            .style(Style::default().bg(Color::DarkGray));
-        f.render_widget(desc_para, sections[2]);
+            .style(Style::default().bg(theme.selected_bg));
+        f.render_widget(desc_para, sections[3]);
     } else {
-        // Show read-only description
+        // Show read-only description as markdown, or raw with search matches highlighted
+        let matches = app.description_matches();
         let desc_text = if task.description.is_empty() {
-            "No description (press Enter to add)"
+            Text::from("No description (press Enter to add)")
+        } else if app.description_search.is_none() {
+            markdown::render(&task.description, &theme)
+        } else {
+            highlight_matches(
+                &task.description,
+                &matches,
+                app.description_search.as_deref().map_or(0, str::len),
+                app.description_match,
+                &theme,
+            )
+        };
+        let desc_title = if app.input_mode == InputMode::SearchingDescription {
+            format!("Description [/{}]", app.input_buffer)
+        } else if matches.is_empty() && app.description_search.is_some() {
+            "Description [no match | Esc: clear]".to_string()
+        } else if let Some(query) = &app.description_search {
+            format!(
+                "Description [/{} {}/{} | n/N: next/prev | Esc: clear]",
+                query,
+                app.description_match + 1,
+                matches.len()
+            )
         } else {
-            &task.description
+            "Description".to_string()
         };
+        // the scroll counts description lines, the paragraph wants wrapped rows
+        let text_width = sections[3].width.saturating_sub(2);
+        let scroll_rows: u16 = desc_text
+            .lines
+            .iter()
+            .take(app.description_scroll)
+            .map(|line| {
+                let shown: String = line
+                    .spans
+                    .iter()
+                    .map(|span| span.content.as_ref())
+                    .collect();
+                wrapped_line_count(&shown, text_width)
+            })
+            .sum();
         let border_style = if is_desc_focused {
             Style::default()
-                .fg(Color::Yellow)
+                .fg(theme.highlight)
                 .add_modifier(Modifier::BOLD)
         } else {
             Style::default()
         };
         let desc_para = Paragraph::new(desc_text)
             .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .title("Description")
+                bordered(app)
+                    .title(desc_title)
+                    .border_type(focus_border(app, is_desc_focused))
                     .border_style(border_style),
             )
-            .wrap(Wrap { trim: false });
-        f.render_widget(desc_para, sections[2]);
+            .wrap(Wrap { trim: false })
+            .scroll((scroll_rows, 0));
+        f.render_widget(desc_para, sections[3]);
     }
 }
 
-// draw help view
-fn draw_help(f: &mut Frame, _app: &mut App) {
-    let area = f.area();
-
-    let block = Block::default()
-        .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Cyan))
-        .title(" Help (Press Esc or ? to close) ");
+// description lines with every match of a search highlighted, the current one reversed
+fn highlight_matches(
+    description: &str,
+    matches: &[usize],
+    match_len: usize,
+    current: usize,
+    theme: &Theme,
+) -> Text<'static> {
+    let mut lines = Vec::new();
+    let mut line_start = 0;
+    for line in description.split('\n') {
+        let line_end = line_start + line.len();
+        let mut spans = Vec::new();
+        let mut pos = line_start;
+        for (i, &start) in matches.iter().enumerate() {
+            let end = start + match_len;
+            if start < line_start || end > line_end {
+                continue;
+            }
+            spans.push(Span::raw(description[pos..start].to_string()));
+            let style = if i == current {
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::REVERSED | Modifier::BOLD)
+            } else {
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::UNDERLINED)
+            };
+            spans.push(Span::styled(description[start..end].to_string(), style));
+            pos = end;
+        }
+        spans.push(Span::raw(description[pos..line_end].to_string()));
+        lines.push(Line::from(spans));
+        line_start = line_end + 1;
+    }
+    Text::from(lines)
+}
 
-    let inner = block.inner(area);
-    f.render_widget(block, area);
+// draw help view
+fn draw_help(f: &mut Frame, app: &mut App, area: Rect) {
+    let theme = app.theme;
 
-    let help_text = vec![
+    let mut help_text = vec![
         Line::from(""),
         Line::from(vec![Span::styled(
             "Navigation:",
             Style::default()
-                .fg(Color::Yellow)
+                .fg(theme.highlight)
                 .add_modifier(Modifier::BOLD),
         )]),
         Line::from("  h/← : Move left (previous column)"),
         Line::from("  j/↓ : Move down (next task)"),
         Line::from("  k/↑ : Move up (previous task)"),
         Line::from("  l/→ : Move right (next column)"),
+        Line::from("  g/G : Jump to first/last task"),
+        Line::from("  5j  : Repeat a move (count prefix, Esc clears)"),
         Line::from(""),
         Line::from(vec![Span::styled(
             "Column Management:",
             Style::default()
-                .fg(Color::Yellow)
+                .fg(theme.highlight)
                 .add_modifier(Modifier::BOLD),
         )]),
         Line::from("  Shift+C : Add new column"),
         Line::from("  Shift+R : Rename current column"),
-        Line::from("  Shift+D : Delete current column (if empty)"),
-        Line::from("  Shift+H/← : Move column left"),
-        Line::from("  Shift+L/→ : Move column right"),
+        Line::from("  Shift+D : Delete current column (asks if it has tasks)"),
+        Line::from("  Ctrl+K  : Clear all tasks from current column (asks y/n)"),
+        Line::from("  Shift+B : Mark/unmark current column as the backlog"),
+        Line::from("  Shift+P : Pull the top backlog card into the first column"),
+        Line::from("  z       : Collapse/expand current column"),
+        Line::from("  c       : Set current column color"),
+        Line::from("  s       : Sort current column by title"),
+        Line::from("  Shift+H/< : Move column left"),
+        Line::from("  Shift+L/> : Move column right"),
         Line::from(""),
         Line::from(vec![Span::styled(
             "Task Management:",
             Style::default()
-                .fg(Color::Yellow)
+                .fg(theme.highlight)
                 .add_modifier(Modifier::BOLD),
         )]),
         Line::from("  Enter : Open task details"),
+        Line::from("  o     : Show/hide the selected card's description"),
+        Line::from("  i     : Show/hide a description line on every card"),
         Line::from("  a     : Add new task to current column"),
+        Line::from("  A     : Add several tasks in a row (Esc when done)"),
         Line::from("  t     : Add tag to selected task"),
+        Line::from("  %     : Rename a tag on every card"),
+        Line::from("  * / # : Add / remove a tag on every card in the column"),
+        Line::from("  Space : Mark task (m/n then move all marked, Esc clears)"),
         Line::from("  m     : Move task forward (to next column)"),
         Line::from("  n     : Move task backward (to previous column)"),
-        Line::from("  d     : Delete selected task"),
+        Line::from("  3M    : Move task to column number (here 3)"),
+        Line::from("  d     : Delete selected task (asks y/n)"),
+        Line::from("  x / p : Cut task / paste it at the selection"),
+        Line::from("  f     : Filter by tag (press again to clear)"),
+        Line::from("  T     : Tag legend (tags in use, Enter filters)"),
+        Line::from("  U     : Upcoming tasks by due date (Enter jumps)"),
+        Line::from("  r     : Rename the selected task in place"),
+        Line::from("  +/-   : Widen/narrow the selected column"),
+        Line::from("  :     : Command line (:add, :tag, :column, :project, :quit, ...)"),
+        Line::from("  Shift+O : Board overview, one dot per card"),
+        Line::from("  X     : Archive selected task (done column)"),
+        Line::from("  v     : View archive (restore tasks)"),
+        Line::from("  Shift+J : Activity log of the project (newest first)"),
         Line::from("  e     : Edit description (when viewing task)"),
         Line::from(""),
         Line::from(vec![Span::styled(
             "Predefined Tags:",
             Style::default()
-                .fg(Color::Yellow)
+                .fg(theme.highlight)
                 .add_modifier(Modifier::BOLD),
         )]),
+    ];
+
+    // predefined tags come straight from the color table so they can't drift apart
+    help_text.extend(PREDEFINED_TAGS.iter().map(|(name, color, description)| {
         Line::from(vec![
             Span::raw("  "),
             Span::styled(
-                "urgent",
-                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
-            ),
-            Span::raw("        : Red - High priority"),
-        ]),
-        Line::from(vec![
-            Span::raw("  "),
-            Span::styled(
-                "security",
-                Style::default()
-                    .fg(Color::LightRed)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Span::raw("      : Light Red - Security work"),
-        ]),
-        Line::from(vec![
-            Span::raw("  "),
-            Span::styled(
-                "bug",
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Span::raw("           : Yellow - Needs fixing"),
-        ]),
-        Line::from(vec![
-            Span::raw("  "),
-            Span::styled(
-                "feature",
-                Style::default()
-                    .fg(Color::Green)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Span::raw("       : Green - New feature"),
-        ]),
-        Line::from(vec![
-            Span::raw("  "),
-            Span::styled(
-                "performance",
-                Style::default()
-                    .fg(Color::LightGreen)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Span::raw("   : Light Green - Optimization"),
-        ]),
-        Line::from(vec![
-            Span::raw("  "),
-            Span::styled(
-                "enhancement",
-                Style::default()
-                    .fg(Color::Blue)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Span::raw("   : Blue - Improvement"),
-        ]),
-        Line::from(vec![
-            Span::raw("  "),
-            Span::styled(
-                "User",
-                Style::default()
-                    .fg(Color::LightBlue)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Span::raw("          : Light Blue - User-facing"),
-        ]),
-        Line::from(vec![
-            Span::raw("  "),
-            Span::styled(
-                "Dev",
-                Style::default()
-                    .fg(Color::Magenta)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Span::raw("           : Magenta - Developer work"),
-        ]),
-        Line::from(vec![
-            Span::raw("  "),
-            Span::styled(
-                "documentation",
-                Style::default()
-                    .fg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Span::raw(" : Cyan - Documentation"),
-        ]),
-        Line::from(vec![
-            Span::raw("  "),
-            Span::styled(
-                "design",
-                Style::default()
-                    .fg(Color::LightCyan)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Span::raw("        : Light Cyan - UI/UX work"),
-        ]),
-        Line::from(vec![
-            Span::raw("  "),
-            Span::styled(
-                "refactor",
-                Style::default()
-                    .fg(Color::LightYellow)
-                    .add_modifier(Modifier::BOLD),
+                format!("{:<13}", name),
+                Style::default().fg(*color).add_modifier(Modifier::BOLD),
             ),
-            Span::raw("      : Light Yellow - Code quality"),
-        ]),
+            Span::raw(format!(" : {}", description)),
+        ])
+    }));
+
+    help_text.extend([
         Line::from(""),
         Line::from(vec![Span::styled(
             "Other:",
             Style::default()
-                .fg(Color::Yellow)
+                .fg(theme.highlight)
                 .add_modifier(Modifier::BOLD),
         )]),
+        Line::from("  Shift+E : Export project to Markdown"),
+        Line::from("  Shift+S : Write status.json (counts for status bars)"),
+        Line::from("  Ctrl+N/B: Next/previous project"),
+        Line::from("  Shift+M : Merge projects (in the project list)"),
+        Line::from("  c       : Project color (in the project list)"),
+        Line::from("  Ctrl+D: Toggle delete confirmation"),
+        Line::from("  Ctrl+T: Toggle light/dark theme"),
         Line::from("  ?     : Show this help"),
         Line::from("  q     : Quit application"),
-    ];
+    ]);
+
+    let block = bordered(app)
+        .border_style(Style::default().fg(theme.accent))
+        .title(" Help ");
+    let inner = block.inner(area);
+    f.render_widget(block, area);
 
-    let help_para = Paragraph::new(help_text);
+    // remember the viewport so scrolling can clamp at the bottom, then draw only the visible slice
+    app.help_page = (inner.height as usize).max(1);
+    app.help_max_scroll = help_text.len().saturating_sub(app.help_page);
+    app.help_scroll = app.help_scroll.min(app.help_max_scroll);
+    let visible: Vec<Line> = help_text
+        .into_iter()
+        .skip(app.help_scroll)
+        .take(app.help_page)
+        .collect();
+
+    let help_para = Paragraph::new(visible);
     f.render_widget(help_para, inner);
 }
 
 // draw project list view
-fn draw_project_list(f: &mut Frame, app: &mut App) {
-    let area = f.area();
+fn draw_project_list(f: &mut Frame, app: &mut App, area: Rect) {
+    let theme = app.theme;
 
     let is_adding = app.input_mode == InputMode::AddingProject;
+    let is_coloring = app.input_mode == InputMode::ColoringProject;
+    let is_moving_task = app.input_mode == InputMode::MovingTaskToProject;
+    let is_picking_template = app.input_mode == InputMode::PickingTemplate;
+
+    let merge_source = app
+        .merge_source
+        .filter(|_| app.input_mode == InputMode::MergingProject)
+        .and_then(|src| app.projects.get(src));
 
     let title = if is_adding {
-        " Projects - ADD NEW (Enter to save, Esc to cancel) "
+        " Projects - ADD NEW "
+    } else if is_coloring {
+        " Projects - COLOR "
+    } else if is_picking_template {
+        " Projects - COLUMNS "
+    } else if is_moving_task {
+        " Move Task to Project "
+    } else if merge_source.is_some() {
+        " Merge Project Into "
+    } else if app.input_mode == InputMode::ConfirmDelete {
+        " Projects - DELETE "
     } else {
-        " Projects (j/k: navigate | Enter: select | a: add | d: delete | Esc: cancel) "
+        " Projects "
     };
 
-    let block = Block::default()
-        .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Cyan))
+    let block = bordered(app)
+        .border_style(overlay_border(&theme, &app.input_mode))
         .title(title);
 
     let inner = block.inner(area);
     f.render_widget(block, area);
 
-    if is_adding {
-        // Show input for new project name
+    if is_adding || is_coloring {
+        // Show input for new project name or the color
         let input_area = Rect {
             x: inner.x,
             y: inner.y,
@@ -704,30 +1775,101 @@ fn draw_project_list(f: &mut Frame, app: &mut App) {
         };
 
         let input_text = vec![Line::from(vec![
-            Span::styled("New Project Name: ", Style::default().fg(Color::Yellow)),
+            Span::styled(
+                if is_adding {
+                    "New Project Name: "
+                } else {
+                    "Project Color (name or #hex, empty to reset): "
+                },
+                Style::default().fg(theme.highlight),
+            ),
             Span::raw(&app.input_buffer),
         ])];
 
         let input_para = Paragraph::new(input_text)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .border_style(Style::default().fg(Color::Yellow)),
-            )
-            .style(Style::default().bg(Color::DarkGray));
+            .block(bordered(app).border_style(Style::default().fg(theme.highlight)))
+            .style(Style::default().bg(theme.selected_bg));
 
         f.render_widget(input_para, input_area);
-    } else {
-        // Show list of projects
+
+        // e.g. a refused duplicate name
+        if let Some(message) = app
+            .flash_text()
+            .filter(|_| inner.height > input_area.height)
+        {
+            let message_area = Rect {
+                y: input_area.y + input_area.height,
+                height: 1,
+                ..input_area
+            };
+            f.render_widget(
+                Paragraph::new(Span::styled(
+                    message.to_string(),
+                    Style::default().fg(theme.danger),
+                )),
+                message_area,
+            );
+        }
+    } else if is_picking_template {
         let mut lines = vec![
             Line::from(Span::styled(
-                "Select a project:",
+                format!("Columns for \"{}\":", app.input_buffer),
                 Style::default()
-                    .fg(Color::Cyan)
+                    .fg(theme.accent)
                     .add_modifier(Modifier::BOLD),
             )),
             Line::from(""),
         ];
+        for (i, (name, _)) in COLUMN_TEMPLATES.iter().enumerate() {
+            let is_selected = i == app.selected_template;
+            let columns: Vec<String> = template_columns(i)
+                .into_iter()
+                .map(|(_, column)| column)
+                .collect();
+            lines.push(Line::from(vec![
+                Span::styled(
+                    if is_selected { "> " } else { "  " },
+                    Style::default()
+                        .fg(theme.highlight)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!("{:<8}", name),
+                    if is_selected {
+                        Style::default().fg(theme.text).add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default().fg(theme.text)
+                    },
+                ),
+                Span::styled(columns.join(" / "), Style::default().fg(theme.muted)),
+            ]));
+        }
+        f.render_widget(Paragraph::new(lines), inner);
+    } else {
+        // Show list of projects
+        let heading = if is_moving_task {
+            "Move task to project:".to_string()
+        } else if let Some(source) = merge_source {
+            format!("Merge \"{}\" into project:", source.name)
+        } else {
+            "Select a project:".to_string()
+        };
+        // the delete prompt replaces the heading, with the list still visible below
+        let heading = match app.delete_prompt() {
+            Some(prompt) => Span::styled(
+                prompt,
+                Style::default()
+                    .fg(theme.danger)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            None => Span::styled(
+                heading,
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        };
+        let mut lines = vec![Line::from(heading), Line::from("")];
 
         for (i, project) in app.projects.iter().enumerate() {
             let is_selected = i == app.selected_project_index;
@@ -740,40 +1882,788 @@ fn draw_project_list(f: &mut Frame, app: &mut App) {
                 spans.push(Span::styled(
                     "> ",
                     Style::default()
-                        .fg(Color::Yellow)
+                        .fg(theme.highlight)
                         .add_modifier(Modifier::BOLD),
                 ));
             } else {
                 spans.push(Span::raw("  "));
             }
 
-            // Project name
+            // Project name, in its own color when one is set
             let style = if is_current {
                 Style::default()
-                    .fg(Color::Green)
+                    .fg(project.accent().unwrap_or(theme.success))
                     .add_modifier(Modifier::BOLD)
             } else if is_selected {
                 Style::default()
-                    .fg(Color::White)
+                    .fg(project.accent().unwrap_or(theme.text))
                     .add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(Color::White)
+                Style::default().fg(project.accent().unwrap_or(theme.text))
             };
 
             spans.push(Span::styled(&project.name, style));
 
             // Current indicator
             if is_current {
-                spans.push(Span::styled(
-                    " (current)",
-                    Style::default().fg(Color::DarkGray),
-                ));
+                spans.push(Span::styled(" (current)", Style::default().fg(theme.muted)));
             }
 
             lines.push(Line::from(spans));
         }
 
+        // e.g. which project is active after a delete
+        if let Some(message) = app.flash_text() {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                message.to_string(),
+                Style::default().fg(theme.success),
+            )));
+        }
+
         let list_para = Paragraph::new(lines);
         f.render_widget(list_para, inner);
     }
 }
+
+// draw archive view
+fn draw_archive(f: &mut Frame, app: &mut App, area: Rect) {
+    let theme = app.theme;
+
+    let restore_target = app
+        .board()
+        .get_column(app.selected_column)
+        .map_or("column", |col| col.name.as_str());
+    let title = format!(" Archive (restores to {}) ", restore_target);
+
+    let block = bordered(app)
+        .border_style(Style::default().fg(theme.accent))
+        .title(title);
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            format!("Archived tasks in {}:", app.project_name()),
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+
+    if app.archived().is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  Nothing archived yet (press X on a done task)",
+            Style::default().fg(theme.muted),
+        )));
+    }
+
+    for (i, task) in app.archived().iter().enumerate() {
+        let is_selected = i == app.selected_archive_index;
+
+        let mut spans = vec![if is_selected {
+            Span::styled(
+                "> ",
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::BOLD),
+            )
+        } else {
+            Span::raw("  ")
+        }];
+
+        let style = if is_selected {
+            Style::default().fg(theme.text).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme.text)
+        };
+        spans.push(Span::styled(task.title.as_str(), style));
+
+        for tag in &task.tags {
+            spans.push(Span::styled(
+                format!(" #{}", tag),
+                Style::default().fg(Task::get_tag_color(tag)),
+            ));
+        }
+
+        lines.push(Line::from(spans));
+    }
+
+    let list_para = Paragraph::new(lines);
+    f.render_widget(list_para, inner);
+}
+
+// draw the activity log of the current project, newest first
+fn draw_activity_log(f: &mut Frame, app: &mut App, area: Rect) {
+    let theme = app.theme;
+
+    let block = bordered(app)
+        .border_style(Style::default().fg(theme.accent))
+        .title(format!(" Activity in {} ", app.project_name()));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let now = datetime::now();
+    let log = &app.projects[app.current_project].log;
+    let mut lines: Vec<Line> = log
+        .iter()
+        .rev()
+        .map(|entry| {
+            Line::from(vec![
+                Span::styled(
+                    format!("{}  ", format_timestamp(app, Some(entry.at))),
+                    Style::default().fg(theme.muted),
+                ),
+                Span::styled(
+                    format!("{:>9}  ", datetime::humanize_duration(now - entry.at)),
+                    Style::default().fg(theme.muted),
+                ),
+                Span::styled(entry.message.clone(), Style::default().fg(theme.text)),
+            ])
+        })
+        .collect();
+    if lines.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "Nothing happened here yet",
+            Style::default().fg(theme.muted),
+        )));
+    }
+
+    // same clamped scrolling as the help view
+    app.log_page = (inner.height as usize).max(1);
+    app.log_max_scroll = lines.len().saturating_sub(app.log_page);
+    app.log_scroll = app.log_scroll.min(app.log_max_scroll);
+    let visible: Vec<Line> = lines
+        .into_iter()
+        .skip(app.log_scroll)
+        .take(app.log_page)
+        .collect();
+    f.render_widget(Paragraph::new(visible), inner);
+}
+
+// draw the tags in use with their colors and card counts
+fn draw_tag_legend(f: &mut Frame, app: &mut App, area: Rect) {
+    let theme = app.theme;
+
+    let block = bordered(app)
+        .border_style(Style::default().fg(theme.accent))
+        .title(" Tags ");
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            format!("Tags in {}:", app.project_name()),
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+
+    let counts = app.board().tag_counts();
+    if counts.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  No tags yet (press t on a task)",
+            Style::default().fg(theme.muted),
+        )));
+    }
+
+    // pad names so the counts line up
+    let name_width = counts.iter().map(|(tag, _)| tag.width()).max().unwrap_or(0) + 1;
+    for (i, (tag, count)) in counts.iter().enumerate() {
+        let is_selected = i == app.selected_legend_index;
+        let marker = if is_selected { "> " } else { "  " };
+        let mut tag_style = Style::default().fg(Task::get_tag_color(tag));
+        if is_selected {
+            tag_style = tag_style.add_modifier(Modifier::BOLD);
+        }
+        lines.push(Line::from(vec![
+            Span::styled(
+                marker,
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(format!("#{:<width$}", tag, width = name_width), tag_style),
+            Span::styled(
+                format!("{} card{}", count, if *count == 1 { "" } else { "s" }),
+                Style::default().fg(theme.muted),
+            ),
+        ]));
+    }
+
+    // which predefined tags this project uses, to help settle on a smaller set
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "Predefined tags:",
+        Style::default()
+            .fg(theme.accent)
+            .add_modifier(Modifier::BOLD),
+    )));
+    for (tag, used) in app.board().predefined_tag_usage() {
+        let line = if used {
+            Line::from(vec![
+                Span::styled("  ✓ ", Style::default().fg(theme.success)),
+                Span::styled(
+                    format!("#{}", tag),
+                    Style::default().fg(Task::get_tag_color(tag)),
+                ),
+            ])
+        } else {
+            Line::from(Span::styled(
+                format!("  · #{} (unused)", tag),
+                Style::default().fg(theme.muted),
+            ))
+        };
+        lines.push(line);
+    }
+
+    f.render_widget(Paragraph::new(lines), inner);
+}
+
+// draw every task of the project by due date
+fn draw_upcoming(f: &mut Frame, app: &mut App, area: Rect) {
+    let theme = app.theme;
+
+    let block = bordered(app)
+        .border_style(Style::default().fg(theme.accent))
+        .title(" Upcoming ");
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            format!("Tasks in {} by due date:", app.project_name()),
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+
+    let board = app.board();
+    let upcoming = app.upcoming_tasks();
+    if upcoming.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  No tasks yet",
+            Style::default().fg(theme.muted),
+        )));
+    }
+
+    // pad dates and column names so the titles line up
+    let date_width = upcoming
+        .iter()
+        .filter_map(|&(col, index)| board.columns[col].tasks[index].due_date.as_deref())
+        .map(|date| format_date(app, date).width())
+        .max()
+        .unwrap_or(0)
+        .max("no date".len())
+        + 2;
+    let column_width = board
+        .columns
+        .iter()
+        .map(|col| col.name.width())
+        .max()
+        .unwrap_or(0)
+        + 2;
+    for (i, &(col, index)) in upcoming.iter().enumerate() {
+        let task = &board.columns[col].tasks[index];
+        let is_selected = i == app.selected_upcoming_index;
+        let (date, date_style) = match &task.due_date {
+            Some(date) => (format_date(app, date), due_style(&theme, date)),
+            None => ("no date".to_string(), Style::default().fg(theme.muted)),
+        };
+        let title_style = if is_selected {
+            Style::default().fg(theme.text).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme.text)
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                if is_selected { "> " } else { "  " },
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(format!("{:<width$}", date, width = date_width), date_style),
+            Span::styled(
+                format!("{:<width$}", board.columns[col].name, width = column_width),
+                Style::default().fg(theme.muted),
+            ),
+            Span::styled(task.title.as_str(), title_style),
+        ]));
+    }
+
+    f.render_widget(Paragraph::new(lines), inner);
+}
+
+// zoomed out board: one cell per card, colored by its most important tag, columns side by side
+fn draw_minimap(f: &mut Frame, app: &mut App, area: Rect) {
+    let theme = app.theme;
+
+    let block = bordered(app)
+        .border_style(Style::default().fg(theme.accent))
+        .title(" Overview ");
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let board = app.board();
+    if board.columns.is_empty() {
+        return;
+    }
+    let areas = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(vec![Constraint::Fill(1); board.columns.len()])
+        .split(inner);
+
+    for (col_idx, column) in board.columns.iter().enumerate() {
+        let area = areas[col_idx];
+        let is_selected_column = col_idx == app.selected_column;
+        let visible = app.visible_task_indices(col_idx);
+
+        let name_style = if is_selected_column {
+            Style::default()
+                .fg(column.accent().unwrap_or(theme.accent))
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(column.accent().unwrap_or(theme.text))
+        };
+        let name = truncate_to_width(
+            &format!("{} ({})", column.name, column.tasks.len()),
+            area.width.saturating_sub(1) as usize,
+        );
+        let mut lines = vec![Line::from(Span::styled(name, name_style))];
+
+        // two cells per card (dot and gap), wrapping within the column
+        let per_row = (area.width as usize / 2).max(1);
+        for row in column.tasks.chunks(per_row).enumerate() {
+            let (row_idx, tasks) = row;
+            let spans: Vec<Span> = tasks
+                .iter()
+                .enumerate()
+                .map(|(i, task)| {
+                    let index = row_idx * per_row + i;
+                    let is_selected = is_selected_column && index == app.selected_index;
+                    if !visible.contains(&index) {
+                        // hidden by the tag filter
+                        return Span::styled("· ", Style::default().fg(theme.muted));
+                    }
+                    let style = Style::default().fg(minimap_color(task, &theme));
+                    if is_selected {
+                        Span::styled("◉ ", style.add_modifier(Modifier::REVERSED))
+                    } else {
+                        Span::styled("● ", style)
+                    }
+                })
+                .collect();
+            lines.push(Line::from(spans));
+        }
+        f.render_widget(Paragraph::new(lines), area);
+    }
+}
+
+// card color in the overview: its own color, else its highest priority predefined tag
+// (urgent first), else its first tag
+fn minimap_color(task: &Task, theme: &Theme) -> Color {
+    task.accent()
+        .or_else(|| {
+            PREDEFINED_TAGS
+                .iter()
+                .find(|(tag, _, _)| task.has_tag(tag))
+                .map(|(_, color, _)| *color)
+        })
+        .or_else(|| task.tags.first().map(|tag| Task::get_tag_color(tag)))
+        .unwrap_or(theme.muted)
+}
+
+// draw blocker picker for the selected task
+fn draw_blocker_picker(f: &mut Frame, app: &mut App, area: Rect) {
+    let theme = app.theme;
+
+    let block = bordered(app)
+        .border_style(Style::default().fg(theme.accent))
+        .title(" Blocked By ");
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let board = app.board();
+    let Some(task) = board
+        .get_column(app.selected_column)
+        .and_then(|col| col.tasks.get(app.selected_index))
+    else {
+        return;
+    };
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            format!("Tasks that must be done before \"{}\":", task.title),
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+
+    for (i, (col_idx, task_idx)) in app.blocker_candidates().into_iter().enumerate() {
+        let column = &board.columns[col_idx];
+        let candidate = &column.tasks[task_idx];
+        let is_selected = i == app.selected_blocker_index;
+        let is_blocker = task.blocked_by.contains(&candidate.id);
+
+        let style = if is_selected {
+            Style::default().fg(theme.text).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme.text)
+        };
+
+        lines.push(Line::from(vec![
+            Span::styled(
+                if is_selected { "> " } else { "  " },
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                if is_blocker { "[x] " } else { "[ ] " },
+                Style::default().fg(theme.highlight),
+            ),
+            Span::styled(candidate.title.as_str(), style),
+            Span::styled(
+                format!("  ({})", column.name),
+                Style::default().fg(theme.muted),
+            ),
+        ]));
+    }
+
+    f.render_widget(Paragraph::new(lines), inner);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::Project;
+
+    #[test]
+    fn test_truncate_wide_title() {
+        let title = "日本語のタスクタイトル🎉🎉";
+        for max_width in 0..30 {
+            let truncated = truncate_to_width(title, max_width);
+            assert!(truncated.width() <= max_width);
+        }
+        assert_eq!(truncate_to_width(title, 7), "日本語…");
+        assert_eq!(truncate_to_width("short", 10), "short");
+    }
+
+    #[test]
+    fn test_wrapped_line_count() {
+        assert_eq!(wrapped_line_count("", 10), 1);
+        assert_eq!(wrapped_line_count("short", 10), 1);
+        assert_eq!(wrapped_line_count("one two three", 9), 2);
+        assert_eq!(wrapped_line_count("abcdefghijkl", 5), 3);
+    }
+
+    // render the app into a test terminal and return the screen as one string
+    fn render(app: &mut App, width: u16, height: u16) -> String {
+        use ratatui::{backend::TestBackend, Terminal};
+
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        terminal.draw(|f| draw(f, app)).unwrap();
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect()
+    }
+
+    // a "Test" project with the default columns and these tasks in one of them
+    fn app_with_tasks(column: usize, titles: &[&str]) -> App {
+        let mut project = Project::new("Test".to_string());
+        for title in titles {
+            project.board.columns[column]
+                .tasks
+                .push(Task::new(title.to_string()));
+        }
+        App::new_with_projects(vec![project])
+    }
+
+    // the tasks of a column, to adjust them after app_with_tasks
+    fn tasks_mut(app: &mut App, column: usize) -> &mut Vec<Task> {
+        &mut app.board_mut().columns[column].tasks
+    }
+
+    #[test]
+    fn test_no_color_mode_has_no_styles() {
+        use ratatui::{backend::TestBackend, Terminal};
+
+        let mut app = app_with_tasks(0, &["Tagged"]);
+        tasks_mut(&mut app, 0)[0].add_tag("urgent".to_string());
+        app.no_color = true;
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 20)).unwrap();
+        terminal.draw(|f| draw(f, &mut app)).unwrap();
+        let buffer = terminal.backend().buffer();
+        assert!(buffer.content().iter().all(|cell| cell.fg == Color::Reset
+            && cell.bg == Color::Reset
+            && cell.modifier.is_empty()));
+        // the selected card is still recognizable
+        let screen: String = buffer.content().iter().map(|cell| cell.symbol()).collect();
+        assert!(screen.contains('╔'));
+    }
+
+    #[test]
+    fn test_card_shows_relative_age() {
+        let mut app = app_with_tasks(0, &["Fresh"]);
+        let mut legacy = Task::new("Legacy".to_string());
+        legacy.updated_at = None;
+        tasks_mut(&mut app, 1).push(legacy);
+
+        let screen = render(&mut app, 100, 20);
+        assert_eq!(screen.matches("just now").count(), 1);
+        assert!(screen.contains("Legacy"));
+    }
+
+    #[test]
+    fn test_rounded_borders_setting() {
+        let mut app = app_with_tasks(0, &[]);
+        assert!(!render(&mut app, 80, 20).contains('╭'));
+        app.settings.border_type = "rounded".to_string();
+        let screen = render(&mut app, 80, 20);
+        assert!(screen.contains('╭'));
+        assert!(!screen.contains('┌'));
+    }
+
+    #[test]
+    fn test_minimap_shows_a_dot_per_card() {
+        let mut app = app_with_tasks(1, &["Task 0", "Task 1", "Task 2"]);
+        app.selected_column = 1;
+        app.selected_index = 2;
+        app.open_minimap();
+
+        let screen = render(&mut app, 80, 10);
+        assert!(screen.contains("In Progress (3)"));
+        assert_eq!(screen.matches('●').count(), 2);
+        assert_eq!(screen.matches('◉').count(), 1);
+    }
+
+    #[test]
+    fn test_more_markers_when_column_overflows() {
+        let mut app = app_with_tasks(0, &[]);
+        for i in 0..10 {
+            tasks_mut(&mut app, 0).push(Task::new(format!("Task {}", i)));
+        }
+
+        let screen = render(&mut app, 80, 30);
+        assert!(screen.contains("▼ 7 more"));
+        assert_eq!(screen.matches(" more").count(), 1);
+
+        app.selected_index = 9;
+        app.update_scroll();
+        let screen = render(&mut app, 80, 30);
+        assert!(screen.contains("▲ 7 more"));
+        assert_eq!(screen.matches(" more").count(), 1);
+    }
+
+    #[test]
+    fn test_description_marker_on_card() {
+        let mut app = app_with_tasks(0, &["Plain"]);
+        assert!(!render(&mut app, 80, 30).contains('≡'));
+
+        tasks_mut(&mut app, 0)[0].description = "notes".to_string();
+        assert!(render(&mut app, 80, 30).contains('≡'));
+    }
+
+    #[test]
+    fn test_expanded_card_shows_description() {
+        let mut app = app_with_tasks(0, &["Task 0", "Task 1", "Task 2"]);
+        for (i, task) in tasks_mut(&mut app, 0).iter_mut().enumerate() {
+            task.description = format!("notes {}\nmore notes {}", i, i);
+        }
+        assert!(!render(&mut app, 120, 30).contains("more notes"));
+
+        app.toggle_expand_selected();
+        let screen = render(&mut app, 120, 30);
+        assert!(screen.contains("more notes 0"));
+        assert!(!screen.contains("more notes 1"));
+        // cards below are pushed down, not dropped
+        assert!(screen.contains("Task 2"));
+
+        // the expansion follows the selection
+        app.move_down();
+        let screen = render(&mut app, 120, 30);
+        assert!(screen.contains("more notes 1"));
+        assert!(!screen.contains("more notes 0"));
+    }
+
+    #[test]
+    fn test_show_descriptions_on_every_card() {
+        let mut app = app_with_tasks(0, &[]);
+        for i in 0..8 {
+            let mut task = Task::new(format!("Task {}", i));
+            task.description = format!("\nfirst line {}\nsecond line {}", i, i);
+            tasks_mut(&mut app, 0).push(task);
+        }
+        let screen = render(&mut app, 120, 40);
+        assert!(!screen.contains("first line"));
+        let titles_only = app.visible_items;
+
+        app.toggle_descriptions();
+        let screen = render(&mut app, 120, 40);
+        assert!(screen.contains("first line 0"));
+        assert!(screen.contains("first line 1"));
+        assert!(!screen.contains("second line"));
+        // taller cards, so fewer of them fit
+        assert!(app.visible_items < titles_only);
+    }
+
+    #[test]
+    fn test_tiny_terminal() {
+        let mut app = app_with_tasks(0, &["Task"]);
+        assert!(render(&mut app, 20, 10).contains("Terminal"));
+
+        // collapsing columns frees up room
+        for i in 1..4 {
+            app.board_mut().columns[i].collapsed = true;
+        }
+        let screen = render(&mut app, 40, 10);
+        assert!(!screen.contains("Terminal"));
+
+        app.open_task();
+        assert!(render(&mut app, 20, 10).contains("Title"));
+    }
+
+    #[test]
+    fn test_key_hints_per_mode() {
+        let mut app = app_with_tasks(0, &["Task"]);
+        let screen = render(&mut app, 200, 20);
+        assert!(screen.contains("h/j/k/l: navigate"));
+
+        app.open_task();
+        assert!(render(&mut app, 200, 20).contains("Tab: switch field"));
+
+        app.close_view();
+        app.open_project_list();
+        assert!(render(&mut app, 200, 20).contains("M: merge"));
+    }
+
+    #[test]
+    fn test_estimates_on_cards_and_column_title() {
+        let mut app = app_with_tasks(0, &["Task", "Task"]);
+        tasks_mut(&mut app, 0)[0].estimate = Some(2.0);
+        tasks_mut(&mut app, 0)[1].estimate = Some(1.5);
+        let screen = render(&mut app, 120, 30);
+        assert!(screen.contains("To Do (2) · 3.5h"));
+        assert!(screen.contains("1.5h"));
+    }
+
+    #[test]
+    fn test_tags_overflow_into_badge() {
+        let tags: Vec<String> = ["ui", "bug", "api", "docs", "perf", "ux", "db", "ops"]
+            .iter()
+            .map(|tag| tag.to_string())
+            .collect();
+        // "#ui #bug " is 9 wide, leaving room for "+6"
+        assert_eq!(fit_tags(&tags, 12), (2, 6));
+        assert_eq!(fit_tags(&tags, 100), (8, 0));
+        assert_eq!(fit_tags(&tags, 2), (0, 8));
+        assert_eq!(fit_tags(&tags[..1], 2), (1, 0));
+
+        let mut app = app_with_tasks(0, &["Tagged"]);
+        tasks_mut(&mut app, 0)[0].tags = tags;
+        let screen = render(&mut app, 80, 30);
+        assert!(screen.contains("#ui"));
+        assert!(screen.contains(" +"));
+        assert!(!screen.contains("#ops"));
+    }
+
+    #[test]
+    fn test_empty_column_hint() {
+        let mut app = app_with_tasks(0, &[]);
+        let screen = render(&mut app, 160, 30);
+        assert_eq!(screen.matches("No tasks — press 'a' to add").count(), 4);
+
+        tasks_mut(&mut app, 0).push(Task::new("First".to_string()));
+        let screen = render(&mut app, 160, 30);
+        assert_eq!(screen.matches("No tasks — press 'a' to add").count(), 3);
+    }
+
+    #[test]
+    fn test_help_lists_every_predefined_tag() {
+        let mut app = app_with_tasks(0, &[]);
+        app.show_help();
+        let screen = render(&mut app, 80, 120);
+        for (name, _, description) in PREDEFINED_TAGS {
+            assert!(screen.contains(&format!("{:<13} : {}", name, description)));
+        }
+    }
+
+    #[test]
+    fn test_help_scrolls_on_short_terminal() {
+        let mut app = app_with_tasks(0, &[]);
+        app.show_help();
+        let screen = render(&mut app, 80, 24);
+        assert!(screen.contains("Navigation:"));
+        assert!(!screen.contains("Quit application"));
+
+        // scrolling far past the end clamps to the last page
+        app.scroll_help_down(1000);
+        let screen = render(&mut app, 80, 24);
+        assert!(screen.contains("Quit application"));
+        assert_eq!(app.help_scroll, app.help_max_scroll);
+
+        app.scroll_help_up(1000);
+        assert_eq!(app.help_scroll, 0);
+    }
+
+    #[test]
+    fn test_long_title_wraps_in_detail_view() {
+        let mut app = app_with_tasks(
+            0,
+            &["A very long task title that cannot fit on one line of a narrow terminal end"],
+        );
+        app.open_task();
+
+        assert!(render(&mut app, 40, 30).contains("terminal end"));
+    }
+
+    #[test]
+    fn test_detail_view_scrolls_through_many_tags() {
+        let mut app = app_with_tasks(0, &["Tagged"]);
+        let task = &mut tasks_mut(&mut app, 0)[0];
+        for i in 1..=20 {
+            task.add_tag(format!("tag{:02}", i));
+        }
+        task.description = "still here".to_string();
+        app.open_task();
+
+        let screen = render(&mut app, 80, 30);
+        assert!(screen.contains("#tag01"));
+        assert!(!screen.contains("#tag20"));
+        assert!(screen.contains("(20, 1-9"));
+        assert!(screen.contains("still here"));
+
+        // every tag can be reached with j and removed, the heading stays put
+        for _ in 0..19 {
+            app.move_tag_down();
+        }
+        let screen = render(&mut app, 80, 30);
+        assert!(screen.contains("#tag20"));
+        assert!(!screen.contains("#tag01"));
+        assert!(screen.contains("(20, 1-9"));
+        assert!(screen.contains("still here"));
+        app.remove_selected_tag();
+        assert!(render(&mut app, 80, 30).contains("#tag19"));
+
+        // a taller terminal shows more of them at once
+        app.selected_tag_index = 0;
+        let screen = render(&mut app, 80, 60);
+        assert!(screen.contains("#tag15"));
+    }
+}
@@ -0,0 +1,58 @@
+// drives the board through App like the key handlers do, without a terminal or disk
+use tui_kanban::app::{App, InputMode};
+use tui_kanban::board::Project;
+
+fn app_with_empty_project() -> App {
+    App::new_with_projects(vec![Project::new("Work".to_string())])
+}
+
+fn add_task(app: &mut App, title: &str) {
+    app.input_mode = InputMode::AddingTask;
+    app.input_buffer = title.to_string();
+    app.submit_input();
+}
+
+fn titles(app: &App, column: usize) -> Vec<&str> {
+    app.board().columns[column]
+        .tasks
+        .iter()
+        .map(|task| task.title.as_str())
+        .collect()
+}
+
+#[test]
+fn add_move_and_delete_tasks() {
+    let mut app = app_with_empty_project();
+    add_task(&mut app, "Write docs");
+    add_task(&mut app, "Fix bug");
+    assert_eq!(titles(&app, 0), ["Write docs", "Fix bug"]);
+    assert_eq!(app.input_mode, InputMode::Normal);
+
+    // the new task is selected, move it along (the cursor stays in its column)
+    app.move_task_forward();
+    app.move_right();
+    app.move_task_forward();
+    assert_eq!(titles(&app, 0), ["Write docs"]);
+    assert_eq!(titles(&app, 2), ["Fix bug"]);
+
+    app.move_right();
+    app.delete_task();
+    assert_eq!(app.input_mode, InputMode::ConfirmDelete);
+    app.confirm_pending_delete();
+    assert!(titles(&app, 2).is_empty());
+}
+
+#[test]
+fn add_column_and_rename_task() {
+    let mut app = app_with_empty_project();
+    app.start_adding_column();
+    app.input_buffer = "Blocked".to_string();
+    app.submit_input();
+    assert_eq!(app.board().columns.last().unwrap().name, "Blocked");
+
+    add_task(&mut app, "Typo");
+    app.start_renaming_task();
+    app.input_buffer = "Fixed".to_string();
+    app.submit_input();
+    assert_eq!(titles(&app, 0), ["Fixed"]);
+}